@@ -0,0 +1,99 @@
+//! Adjective declension for the adjective quiz
+//! Builds phrases like "dem großen Hund" from an article type, a gender and a case
+
+use dewiktionary::artikel::{artikel, Artikelart, Genus};
+use dewiktionary::parser::{Kasus, Numerus};
+
+/// Definite articles (der/die/das) take the weak endings, indefinite ones (ein/eine) the mixed ones
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ArticleKind {
+    Definite,
+    Indefinite,
+}
+
+/// The singular column of the 'derdiedas' table that holds the noun in a case
+pub fn singular_column(kasus: Kasus) -> String {
+    format!("{}_singular", kasus.name())
+}
+
+/// The singular article for a gender ("m", "f" or "n") in a case
+pub fn article(kind: ArticleKind, genus: &str, kasus: Kasus) -> String {
+    let art = match kind {
+        ArticleKind::Definite => Artikelart::Bestimmt,
+        ArticleKind::Indefinite => Artikelart::Unbestimmt,
+    };
+    Genus::aus_kuerzel(genus)
+        .and_then(|genus| artikel(art, genus, kasus, Numerus::Singular))
+        .unwrap_or_default()
+}
+
+/// The ending of an adjective after an article, e.g. "en" in "dem großen Hund"
+pub fn adjective_ending(kind: ArticleKind, genus: &str, kasus: Kasus) -> &'static str {
+    match (kind, kasus, genus) {
+        (_, Kasus::Dativ | Kasus::Genitiv, _) => "en",
+        (_, Kasus::Akkusativ, "m") => "en",
+        (ArticleKind::Definite, _, _) => "e",
+        (ArticleKind::Indefinite, Kasus::Nominativ, "m") => "er",
+        (ArticleKind::Indefinite, _, "n") => "es",
+        (ArticleKind::Indefinite, _, _) => "e",
+    }
+}
+
+/// The stem an ending is added to: "dunkel" gives "dunkl", "teuer" gives "teur" and "hoch" gives "hoh".
+/// Only an unstressed "-el" after a consonant loses its "e", so "parallel" keeps it
+pub fn adjective_stem(adjective: &str) -> String {
+    if adjective == "hoch" {
+        return "hoh".to_string();
+    }
+    if let Some(stem) = adjective.strip_suffix("el") {
+        let before = stem.chars().last().unwrap_or_default();
+        if before != 'l' && "bcdfghjkmnpqrstvwxzß".contains(before) {
+            return format!("{}l", stem);
+        }
+    }
+    if adjective.ends_with("euer") || adjective.ends_with("auer") {
+        return format!("{}r", &adjective[..adjective.len() - 2]);
+    }
+    adjective.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn phrase(kind: ArticleKind, adjective: &str, genus: &str, kasus: Kasus, noun: &str) -> String {
+        format!(
+            "{} {}{} {}",
+            article(kind, genus, kasus),
+            adjective_stem(adjective),
+            adjective_ending(kind, genus, kasus),
+            noun
+        )
+    }
+
+    #[test]
+    fn test_definite_phrases() {
+        assert_eq!(phrase(ArticleKind::Definite, "groß", "m", Kasus::Nominativ, "Hund"), "der große Hund");
+        assert_eq!(phrase(ArticleKind::Definite, "groß", "m", Kasus::Akkusativ, "Hund"), "den großen Hund");
+        assert_eq!(phrase(ArticleKind::Definite, "klein", "f", Kasus::Dativ, "Katze"), "der kleinen Katze");
+        assert_eq!(phrase(ArticleKind::Definite, "alt", "n", Kasus::Genitiv, "Hauses"), "des alten Hauses");
+    }
+
+    #[test]
+    fn test_indefinite_phrases() {
+        assert_eq!(phrase(ArticleKind::Indefinite, "groß", "m", Kasus::Nominativ, "Hund"), "ein großer Hund");
+        assert_eq!(phrase(ArticleKind::Indefinite, "klein", "n", Kasus::Akkusativ, "Haus"), "ein kleines Haus");
+        assert_eq!(phrase(ArticleKind::Indefinite, "neu", "f", Kasus::Nominativ, "Tasche"), "eine neue Tasche");
+        assert_eq!(phrase(ArticleKind::Indefinite, "alt", "m", Kasus::Dativ, "Baum"), "einem alten Baum");
+    }
+
+    #[test]
+    fn test_adjective_stem() {
+        assert_eq!(adjective_stem("dunkel"), "dunkl");
+        assert_eq!(adjective_stem("edel"), "edl");
+        assert_eq!(adjective_stem("parallel"), "parallel");
+        assert_eq!(adjective_stem("teuer"), "teur");
+        assert_eq!(adjective_stem("hoch"), "hoh");
+        assert_eq!(adjective_stem("groß"), "groß");
+    }
+}
@@ -7,6 +7,13 @@ use spin_sdk::{
     sqlite::{Connection, Value},
 };
 
+use dewiktionary::artikel::{artikel, Artikelart, Genus};
+use dewiktionary::parser::{Kasus, Numerus};
+use dewiktionary::vorhersage::{erklaere, sage_voraus};
+use dewiktionary::zerlegung::kandidaten;
+use std::collections::HashMap;
+
+mod adjectives;
 mod sentences;
 mod kid_id;
 
@@ -15,6 +22,15 @@ mod kid_id;
 struct DerDieDas {
     nominativ_singular: String,
     genus: String,
+    /// The nominative article for the genus: "der", "die" or "das"
+    #[serde(default)]
+    article: String,
+    /// Optional hint: the meanings of the word, if the database has them
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    bedeutungen: Vec<String>,
+    /// Optional: the word in the language the learner picked with `?lang=en`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    translation: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -50,6 +66,9 @@ fn handle_request(req: Request) -> Result<impl IntoResponse> {
     router.get("/api/sentence/:word", get_example_sentence);
     router.get("/api/syllable-quiz", get_syllable_quiz);
     router.get("/api/category-quiz", get_category_quiz);
+    router.get("/api/comparison-quiz", get_comparison_quiz);
+    router.get("/api/adjective-quiz", get_adjective_quiz);
+    router.get("/api/hint/:word", get_hint);
     router.post("/api/session/create", create_session);
     router.post("/api/session/join", join_session);
     router.get("/api/session/:id", get_session);
@@ -58,36 +77,36 @@ fn handle_request(req: Request) -> Result<impl IntoResponse> {
     Ok(router.handle(req))
 }
 
-/// Get a random German noun entry
-fn get_random_entry(_req: Request, _params: spin_sdk::http::Params) -> Result<impl IntoResponse> {
+/// Get a random German noun entry, e.g. `/api/entry.json?lang=en` adds the English translation
+fn get_random_entry(req: Request, _params: spin_sdk::http::Params) -> Result<impl IntoResponse> {
     let connection = Connection::open_default()?;
+    let lang = query_param(&req, "lang");
 
     // Try new 'words' table first, fall back to legacy 'derdiedas' table
     let rowset = connection.execute(
-        "SELECT word as nominativ_singular,
-                CASE article
-                    WHEN 'der' THEN 'm'
-                    WHEN 'die' THEN 'f'
-                    WHEN 'das' THEN 'n'
-                    ELSE 'm'
-                END as genus
+        "SELECT word as nominativ_singular, article
          FROM words
          ORDER BY RANDOM()
          LIMIT 1",
         &[],
     );
 
-    let entries: Vec<DerDieDas> = match rowset {
+    let mut entries: Vec<DerDieDas> = match rowset {
         Ok(rs) => rs.rows()
             .map(|row| DerDieDas {
                 nominativ_singular: row.get::<&str>("nominativ_singular").unwrap().to_owned(),
-                genus: row.get::<&str>("genus").unwrap().to_owned(),
+                genus: genus_from_article(row.get::<&str>("article").unwrap_or_default()),
+                article: String::new(),
+                bedeutungen: Vec::new(),
+                translation: None,
             })
             .collect(),
         Err(_) => {
-            // Fall back to legacy table
+            // Fall back to legacy table, without pluralia tantum like "Leute" that have no gender
             let rowset_legacy = connection.execute(
-                "SELECT nominativ_singular, genus FROM derdiedas ORDER BY RANDOM() LIMIT 1",
+                "SELECT nominativ_singular, genus FROM derdiedas
+                 WHERE genus IN ('m', 'f', 'n')
+                 ORDER BY RANDOM() LIMIT 1",
                 &[],
             )?;
 
@@ -95,11 +114,22 @@ fn get_random_entry(_req: Request, _params: spin_sdk::http::Params) -> Result<im
                 .map(|row| DerDieDas {
                     nominativ_singular: row.get::<&str>("nominativ_singular").unwrap().to_owned(),
                     genus: row.get::<&str>("genus").unwrap().to_owned(),
+                    article: String::new(),
+                    bedeutungen: Vec::new(),
+                    translation: None,
                 })
                 .collect()
         }
     };
 
+    for entry in &mut entries {
+        entry.article = definite_article(&entry.genus);
+        entry.bedeutungen = lookup_bedeutungen(&connection, &entry.nominativ_singular, &entry.genus);
+        if let Some(lang) = lang {
+            entry.translation = lookup_translation(&connection, &entry.nominativ_singular, &entry.genus, lang);
+        }
+    }
+
     Ok(Response::builder()
         .status(200)
         .header("content-type", "application/json")
@@ -107,13 +137,78 @@ fn get_random_entry(_req: Request, _params: spin_sdk::http::Params) -> Result<im
         .build())
 }
 
-/// Get an example sentence for a given word
-fn get_example_sentence(_req: Request, params: spin_sdk::http::Params) -> Result<impl IntoResponse> {
+/// A non-empty parameter of the query string, e.g. "en" for `lang` in `?lang=en`
+fn query_param<'a>(req: &'a Request, name: &str) -> Option<&'a str> {
+    req.query()
+        .split('&')
+        .find_map(|pair| pair.strip_prefix(name)?.strip_prefix('='))
+        .filter(|value| !value.is_empty())
+}
+
+/// The genus ("m", "f" or "n") for an article of the 'words' table, "m" if the article is unknown
+fn genus_from_article(article: &str) -> String {
+    Genus::aus_artikel(article).unwrap_or(Genus::Maskulinum).kuerzel().to_string()
+}
+
+/// The nominative article for a genus, e.g. "die" for "f", or "" for an unknown genus
+fn definite_article(genus: &str) -> String {
+    Genus::aus_kuerzel(genus)
+        .and_then(|genus| artikel(Artikelart::Bestimmt, genus, Kasus::Nominativ, Numerus::Singular))
+        .unwrap_or_default()
+}
+
+/// Look up the meanings of a noun, e.g. to tell "die Kiefer" (tree) from "der Kiefer" (jaw).
+/// Databases without the imported 'derdiedas_bedeutung' table give no meanings.
+fn lookup_bedeutungen(connection: &Connection, word: &str, genus: &str) -> Vec<String> {
+    let rowset = connection.execute(
+        "SELECT b.text
+         FROM derdiedas_bedeutung b JOIN derdiedas d ON d.id = b.derdiedas_id
+         WHERE d.nominativ_singular = ? AND d.genus = ?
+         ORDER BY b.rowid",
+        &[Value::Text(word.to_string()), Value::Text(genus.to_string())],
+    );
+
+    match rowset {
+        Ok(rs) => rs.rows()
+            .filter_map(|row| row.get::<&str>("text").map(|s| s.to_owned()))
+            .collect(),
+        Err(_) => Vec::new(),
+    }
+}
+
+/// Look up the first translation of a noun into a language, e.g. "en" or "tr", preferring its
+/// first meaning. Databases without the imported 'derdiedas_uebersetzung' table give none.
+fn lookup_translation(connection: &Connection, word: &str, genus: &str, lang: &str) -> Option<String> {
+    let rowset = connection.execute(
+        "SELECT u.text
+         FROM derdiedas_uebersetzung u JOIN derdiedas d ON d.id = u.derdiedas_id
+         WHERE d.nominativ_singular = ? AND d.genus = ? AND u.sprache = ?
+         ORDER BY u.bedeutungen, u.nummer
+         LIMIT 1",
+        &[
+            Value::Text(word.to_string()),
+            Value::Text(genus.to_string()),
+            Value::Text(lang.to_string()),
+        ],
+    ).ok()?;
+
+    let row = rowset.rows().next()?;
+    row.get::<&str>("text").map(|s| s.to_owned())
+}
+
+/// Get an example sentence for a given word, e.g. `/api/sentence/See?genus=f` for "die See".
+/// Without `genus`, the first genus of the word in the database is used. `art` picks the kind of
+/// noun, e.g. "substantiv" or "eigenname", if the word is both.
+fn get_example_sentence(req: Request, params: spin_sdk::http::Params) -> Result<impl IntoResponse> {
     #[derive(Serialize)]
     struct SentenceResponse {
         word: String,
         sentence: String,
         cached: bool,
+        curated: bool,
+        /// The sentence with the article of the word replaced by "___"
+        #[serde(skip_serializing_if = "Option::is_none")]
+        masked: Option<String>,
     }
 
     let word = params.get("word")
@@ -122,47 +217,22 @@ fn get_example_sentence(_req: Request, params: spin_sdk::http::Params) -> Result
     // First, look up the genus for this word from the database
     let connection = Connection::open_default()?;
 
-    // Try new 'words' table first
-    let genus = match connection.execute(
-        "SELECT CASE article
-                    WHEN 'der' THEN 'm'
-                    WHEN 'die' THEN 'f'
-                    WHEN 'das' THEN 'n'
-                    ELSE 'm'
-                END as genus
-         FROM words WHERE word = ? LIMIT 1",
-        &[Value::Text(word.to_string())],
-    ) {
-        Ok(rs) => {
-            let rows: Vec<_> = rs.rows().collect();
-            rows.first()
-                .and_then(|row| row.get::<&str>("genus"))
-                .map(|s| s.to_owned())
-                .unwrap_or_else(|| "m".to_string())
-        },
-        Err(_) => {
-            // Fall back to legacy table
-            match connection.execute(
-                "SELECT genus FROM derdiedas WHERE nominativ_singular = ? LIMIT 1",
-                &[Value::Text(word.to_string())],
-            ) {
-                Ok(rs) => {
-                    let rows: Vec<_> = rs.rows().collect();
-                    rows.first()
-                        .and_then(|row| row.get::<&str>("genus"))
-                        .map(|s| s.to_owned())
-                        .unwrap_or_else(|| "m".to_string())
-                },
-                Err(_) => "m".to_string()
-            }
-        }
+    let genus = match query_param(&req, "genus") {
+        Some(genus) => genus.to_string(),
+        None => lookup_genus(&connection, word),
     };
 
     // Try to get from cache first
-    let cache_key = format!("sentence:{}", word.to_lowercase());
+    // Homonyms like "der See" and "die See" get their own sentences
+    let cache_key = format!("sentence:{}:{}", word.to_lowercase(), genus);
     let store = Store::open_default()?;
 
-    let (sentence, cached) = if let Ok(Some(data)) = store.get(&cache_key) {
+    // Human-written sentences from Wiktionary come before the cache and the LLM
+    let curated = lookup_curated_sentence(&connection, word, &genus, query_param(&req, "art"));
+
+    let (sentence, cached) = if let Some(curated) = &curated {
+        (curated.text.clone(), false)
+    } else if let Ok(Some(data)) = store.get(&cache_key) {
         // Got from cache
         let cached_sentences: Vec<String> = serde_json::from_slice(&data)?;
         // Return a random one from the cache
@@ -197,6 +267,8 @@ fn get_example_sentence(_req: Request, params: spin_sdk::http::Params) -> Result
         word: word.to_string(),
         sentence,
         cached,
+        curated: curated.is_some(),
+        masked: curated.and_then(|c| c.masked),
     };
 
     Ok(Response::builder()
@@ -206,6 +278,82 @@ fn get_example_sentence(_req: Request, params: spin_sdk::http::Params) -> Result
         .build())
 }
 
+/// The first genus of a word in the database, "m" if the word is unknown
+fn lookup_genus(connection: &Connection, word: &str) -> String {
+    // Try new 'words' table first
+    match connection.execute(
+        "SELECT article FROM words WHERE word = ? LIMIT 1",
+        &[Value::Text(word.to_string())],
+    ) {
+        Ok(rs) => {
+            let rows: Vec<_> = rs.rows().collect();
+            genus_from_article(rows.first().and_then(|row| row.get::<&str>("article")).unwrap_or_default())
+        },
+        Err(_) => {
+            // Fall back to legacy table
+            match connection.execute(
+                "SELECT genus FROM derdiedas WHERE nominativ_singular = ? LIMIT 1",
+                &[Value::Text(word.to_string())],
+            ) {
+                Ok(rs) => {
+                    let rows: Vec<_> = rs.rows().collect();
+                    rows.first()
+                        .and_then(|row| row.get::<&str>("genus"))
+                        .map(|s| s.to_owned())
+                        .unwrap_or_else(|| "m".to_string())
+                },
+                Err(_) => "m".to_string()
+            }
+        }
+    }
+}
+
+/// An example sentence imported from the Wiktionary dump
+struct CuratedSentence {
+    text: String,
+    masked: Option<String>,
+}
+
+/// Look up a random imported example sentence for a word with the given genus, and of the given
+/// kind of noun if there is one, so "der See" does not get a sentence about "die See".
+/// Long quotes from newspapers and books are skipped, short sentences suit kids better.
+/// Databases without the imported 'derdiedas_beispiel' table give no sentence.
+fn lookup_curated_sentence(
+    connection: &Connection,
+    word: &str,
+    genus: &str,
+    art: Option<&str>,
+) -> Option<CuratedSentence> {
+    let rowset = connection.execute(
+        "SELECT b.text, b.maskiert
+         FROM derdiedas_beispiel b JOIN derdiedas d ON d.id = b.derdiedas_id
+         WHERE d.nominativ_singular = ? AND d.genus = ? AND d.art = COALESCE(?, d.art)
+           AND LENGTH(b.text) <= 120
+         ORDER BY RANDOM()
+         LIMIT 1",
+        &[
+            Value::Text(word.to_string()),
+            Value::Text(genus.to_string()),
+            art.map_or(Value::Null, |art| Value::Text(art.to_string())),
+        ],
+    );
+    let rowset = match rowset {
+        Ok(rowset) => rowset,
+        Err(e) => {
+            eprintln!("Looking up an example sentence for {} failed: {:?}", word, e);
+            return None;
+        }
+    };
+
+    let rows: Vec<_> = rowset.rows().collect();
+    rows.first().and_then(|row| {
+        Some(CuratedSentence {
+            text: row.get::<&str>("text")?.to_owned(),
+            masked: row.get::<&str>("maskiert").map(|s| s.to_owned()),
+        })
+    })
+}
+
 /// Create a new game session
 fn create_session(req: Request, _params: spin_sdk::http::Params) -> Result<impl IntoResponse> {
     let body: CreateSessionRequest = serde_json::from_slice(req.body())?;
@@ -405,17 +553,20 @@ fn get_syllable_quiz(_req: Request, _params: spin_sdk::http::Params) -> Result<i
     #[derive(Serialize)]
     struct SyllableQuiz {
         word: String,
-        syllable_count: i32,
+        syllables: Vec<String>,
+        syllable_count: usize,
         difficulty: i32,
     }
 
     let connection = Connection::open_default()?;
 
-    // Get a random word with syllable data
+    // Get a random word with syllables from its Worttrennung, e.g. "Kopf·nuss".
+    // Syllables the importer computed itself are left out, the quiz asks for real ones.
+    // 'difficulty' is only there after tools/enrich_database.py, so all columns are read.
     let rowset = connection.execute(
-        "SELECT nominativ_singular, syllable_count, difficulty
-         FROM derdiedas
-         WHERE syllable_count IS NOT NULL AND syllable_count > 0
+        "SELECT d.*, w.silben
+         FROM derdiedas d JOIN derdiedas_worttrennung w ON w.derdiedas_id = d.id
+         WHERE w.numerus = 'singular' AND w.nummer = 1 AND NOT w.berechnet
          ORDER BY RANDOM() LIMIT 1",
         &[],
     )?;
@@ -423,9 +574,15 @@ fn get_syllable_quiz(_req: Request, _params: spin_sdk::http::Params) -> Result<i
     let rows: Vec<_> = rowset.rows().collect();
 
     if let Some(row) = rows.first() {
+        let syllables: Vec<String> = row.get::<&str>("silben")
+            .unwrap()
+            .split('·')
+            .map(|s| s.to_owned())
+            .collect();
         let quiz = SyllableQuiz {
             word: row.get::<&str>("nominativ_singular").unwrap().to_owned(),
-            syllable_count: row.get::<i32>("syllable_count").unwrap_or(1),
+            syllable_count: syllables.len(),
+            syllables,
             difficulty: row.get::<i32>("difficulty").unwrap_or(1),
         };
 
@@ -516,3 +673,219 @@ fn get_category_quiz(_req: Request, _params: spin_sdk::http::Params) -> Result<i
             .build())
     }
 }
+
+/// Get a comparison quiz question, e.g. "groß – größer – am größten"
+fn get_comparison_quiz(_req: Request, _params: spin_sdk::http::Params) -> Result<impl IntoResponse> {
+    #[derive(Serialize)]
+    struct ComparisonQuiz {
+        positive: String,
+        comparative: String,
+        superlative: String,
+    }
+
+    let connection = Connection::open_default()?;
+
+    // Databases without the imported 'adjektiv' table have no adjectives
+    let rowset = connection.execute(
+        "SELECT positiv, komparativ, superlativ
+         FROM adjektiv
+         WHERE komparativ IS NOT NULL AND superlativ IS NOT NULL
+         ORDER BY RANDOM() LIMIT 1",
+        &[],
+    ).ok();
+    let rows: Vec<_> = rowset.iter().flat_map(|rs| rs.rows()).collect();
+
+    if let Some(row) = rows.first() {
+        let quiz = ComparisonQuiz {
+            positive: row.get::<&str>("positiv").unwrap().to_owned(),
+            comparative: row.get::<&str>("komparativ").unwrap().to_owned(),
+            superlative: format!("am {}", row.get::<&str>("superlativ").unwrap()),
+        };
+
+        Ok(Response::builder()
+            .status(200)
+            .header("content-type", "application/json")
+            .body(serde_json::to_string(&quiz)?)
+            .build())
+    } else {
+        Ok(Response::builder()
+            .status(404)
+            .body("No adjectives with comparison found".to_string())
+            .build())
+    }
+}
+
+/// Get an adjective declension quiz question: the ending of an adjective between an article
+/// and a noun, e.g. "dem groß___ Hund" with the answer "en"
+fn get_adjective_quiz(_req: Request, _params: spin_sdk::http::Params) -> Result<impl IntoResponse> {
+    use adjectives::{adjective_ending, adjective_stem, article, singular_column, ArticleKind};
+
+    #[derive(Serialize)]
+    struct AdjectiveQuiz {
+        noun: String,
+        genus: String,
+        case: Kasus,
+        article: String,
+        adjective: String,
+        stem: String,
+        ending: String,
+        phrase: String,
+        options: Vec<String>,
+    }
+
+    let connection = Connection::open_default()?;
+
+    // Pick the article type and the case from the clock, like the category quiz shuffles
+    use std::time::SystemTime;
+    let seed = SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)?
+        .subsec_nanos() as usize;
+    let kind = [ArticleKind::Definite, ArticleKind::Indefinite][seed % 2];
+    let case = Kasus::ALLE[(seed / 2) % Kasus::ALLE.len()];
+
+    let adjective_rowset = connection.execute(
+        "SELECT positiv FROM adjektiv ORDER BY RANDOM() LIMIT 1",
+        &[],
+    ).ok();
+    let adjective_rows: Vec<_> = adjective_rowset.iter().flat_map(|rs| rs.rows()).collect();
+    let noun_rowset = connection.execute(
+        &format!(
+            "SELECT nominativ_singular, genus, {} AS form
+             FROM derdiedas
+             WHERE genus IN ('m', 'f', 'n')
+             ORDER BY RANDOM() LIMIT 1",
+            singular_column(case)
+        ),
+        &[],
+    )?;
+    let noun_rows: Vec<_> = noun_rowset.rows().collect();
+
+    if let (Some(adjective_row), Some(noun_row)) = (adjective_rows.first(), noun_rows.first()) {
+        let adjective = adjective_row.get::<&str>("positiv").unwrap().to_owned();
+        let genus = noun_row.get::<&str>("genus").unwrap().to_owned();
+        let noun = noun_row
+            .get::<&str>("form")
+            .or(noun_row.get::<&str>("nominativ_singular"))
+            .unwrap()
+            .to_owned();
+        let article = article(kind, &genus, case);
+        let stem = adjective_stem(&adjective);
+        let ending = adjective_ending(kind, &genus, case).to_string();
+        let phrase = format!("{} {}{} {}", article, stem, ending, noun);
+
+        let quiz = AdjectiveQuiz {
+            noun,
+            genus,
+            case,
+            article,
+            adjective,
+            stem,
+            ending,
+            phrase,
+            options: ["e", "en", "er", "es", "em"].iter().map(|s| s.to_string()).collect(),
+        };
+
+        Ok(Response::builder()
+            .status(200)
+            .header("content-type", "application/json")
+            .body(serde_json::to_string(&quiz)?)
+            .build())
+    } else {
+        Ok(Response::builder()
+            .status(404)
+            .body("No adjectives or nouns found".to_string())
+            .build())
+    }
+}
+
+/// The longest word `/api/hint/:word` explains. The word and every piece of it of three or
+/// more letters are parameters of one query, at most 741 for 40 letters, and SQLite allows 999.
+const MAX_HINT_WORD_LENGTH: usize = 40;
+
+/// Explain the article of a word with a rule, e.g. `/api/hint/Zeitung` gives
+/// "Wörter auf -ung haben immer den Artikel „die“: die Zeitung."
+/// Compounds like "Haustür" are explained by their parts if they are in the database.
+fn get_hint(_req: Request, params: spin_sdk::http::Params) -> Result<impl IntoResponse> {
+    #[derive(Serialize)]
+    struct Hint {
+        word: String,
+        genus: String,
+        article: String,
+        confidence: f32,
+        rule: String,
+        /// Whether the word is known and has another article than the rule gives.
+        exception: bool,
+    }
+
+    let word = params.get("word")
+        .ok_or_else(|| anyhow::anyhow!("Missing word parameter"))?;
+    if word.chars().count() > MAX_HINT_WORD_LENGTH {
+        return Ok(Response::builder()
+            .status(400)
+            .body(format!("Words are at most {} letters long", MAX_HINT_WORD_LENGTH))
+            .build());
+    }
+    let connection = Connection::open_default()?;
+
+    // The word itself, so a known noun gets its real article, and the nouns that are pieces
+    // of the word to explain a compound
+    let parts: Vec<Value> = std::iter::once(word.to_owned())
+        .chain(kandidaten(word))
+        .map(Value::Text)
+        .collect();
+    let placeholders = vec!["?"; parts.len()].join(", ");
+    let rowset = connection.execute(
+        &format!(
+            "SELECT nominativ_singular, genus FROM derdiedas WHERE nominativ_singular IN ({})",
+            placeholders
+        ),
+        &parts,
+    )?;
+    let mut lexikon = HashMap::new();
+    let mut genera = Vec::new();
+    for row in rowset.rows() {
+        let noun = row.get::<&str>("nominativ_singular");
+        let genus = row.get::<&str>("genus").and_then(Genus::aus_kuerzel);
+        match (noun, genus) {
+            (Some(noun), Some(genus)) if noun == word => genera.push(genus),
+            (Some(noun), Some(genus)) => {
+                lexikon.insert(noun.to_owned(), genus);
+            }
+            _ => {}
+        }
+    }
+
+    // A known noun is explained with its own gender, an unknown one gets the guess
+    let explained = if genera.is_empty() {
+        sage_voraus(word, &lexikon).map(|vorhersage| {
+            (vorhersage.genus, vorhersage.sicherheit, vorhersage.regel, false)
+        })
+    } else {
+        erklaere(word, &genera, &lexikon).map(|erklaerung| {
+            (erklaerung.genus, erklaerung.sicherheit, erklaerung.regel, erklaerung.ausnahme)
+        })
+    };
+
+    if let Some((genus, confidence, rule, exception)) = explained {
+        let genus = genus.kuerzel().to_owned();
+        let hint = Hint {
+            word: word.to_owned(),
+            article: definite_article(&genus),
+            genus,
+            confidence,
+            rule,
+            exception,
+        };
+
+        Ok(Response::builder()
+            .status(200)
+            .header("content-type", "application/json")
+            .body(serde_json::to_string(&hint)?)
+            .build())
+    } else {
+        Ok(Response::builder()
+            .status(404)
+            .body("No rule found for this word".to_string())
+            .build())
+    }
+}
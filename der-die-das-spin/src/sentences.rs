@@ -1,5 +1,7 @@
 /// Example sentence generation module using Spin Serverless AI
 /// Provides contextual German sentences for learning without revealing der/die/das
+use dewiktionary::artikel::{artikel, Artikelart, Genus};
+use dewiktionary::parser::{Kasus, Numerus};
 use serde::{Deserialize, Serialize};
 use spin_sdk::llm;
 
@@ -65,12 +67,17 @@ Jetzt erstelle einen Satz mit '{}':",
 /// Fallback sentence generation using simple templates
 /// Used when LLM is unavailable or returns invalid results
 fn generate_fallback_sentence(word: &str, genus: &str) -> String {
-    match genus {
-        "m" => format!("Ein Capybara sitzt neben dem {}.", word),
-        "f" => format!("Ein Capybara springt über die {}.", word),
-        "n" => format!("Ein Capybara steht neben dem {}.", word),
-        _ => format!("Capybaras lieben {}!", word),
-    }
+    let Some(genus) = Genus::aus_kuerzel(genus) else {
+        return format!("Capybaras lieben {}!", word);
+    };
+    // Each template puts the word after a preposition, so the nominative article stays hidden
+    let (start, kasus) = match genus {
+        Genus::Maskulinum => ("Ein Capybara sitzt neben", Kasus::Dativ),
+        Genus::Femininum => ("Ein Capybara springt über", Kasus::Akkusativ),
+        Genus::Neutrum => ("Ein Capybara steht neben", Kasus::Dativ),
+    };
+    let article = artikel(Artikelart::Bestimmt, genus, kasus, Numerus::Singular).unwrap_or_default();
+    format!("{} {} {}.", start, article, word)
 }
 
 /// Get multiple sentence variations for a word using LLM
@@ -114,6 +121,7 @@ mod tests {
         let sentence = generate_fallback_sentence("Katze", "f");
         assert!(sentence.contains("Katze"));
         assert!(!sentence.starts_with("Die Katze"));
+        assert_eq!(sentence, "Ein Capybara springt über die Katze.");
     }
 
     #[test]
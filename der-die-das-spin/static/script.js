@@ -320,15 +320,8 @@ function loadJSON() {
 }
 
 function displayWord(entry) {
-    // Set the correct article
-    let correctArticle;
-    if (entry.genus === "m") {
-        correctArticle = "Der";
-    } else if (entry.genus === "f") {
-        correctArticle = "Die";
-    } else if (entry.genus === "n") {
-        correctArticle = "Das";
-    }
+    // Set the correct article, the server derives it from the genus
+    const correctArticle = entry.article.charAt(0).toUpperCase() + entry.article.slice(1);
 
     document.getElementById("genus").textContent = correctArticle;
     document.getElementById("genus").className = "genus";
@@ -426,6 +419,7 @@ async function submitAnswer(correct) {
 // Example Sentence Feature
 async function showExampleSentence() {
     const word = gameState.currentWord?.nominativ_singular;
+    const genus = gameState.currentWord?.genus;
     if (!word) {
         showError('Kein Wort ausgewählt!');
         return;
@@ -442,7 +436,7 @@ async function showExampleSentence() {
     container.classList.remove('hidden');
 
     try {
-        const response = await fetch(`/api/sentence/${encodeURIComponent(word)}`);
+        const response = await fetch(`/api/sentence/${encodeURIComponent(word)}?genus=${encodeURIComponent(genus || '')}`);
 
         if (!response.ok) {
             throw new Error('Fehler beim Laden des Satzes');
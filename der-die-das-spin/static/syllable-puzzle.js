@@ -46,9 +46,8 @@ function checkSyllableAnswer(userAnswer) {
     const word = syllableGameState.currentQuestion.word;
     const silben = correct > 1 ? 'n' : '';
 
-    // Split word into syllables for display
-    const syllables = splitIntoSyllables(word, correct);
-    const syllableDisplay = syllables.join(' • ');
+    // The syllables from the Worttrennung of the dictionary
+    const syllableDisplay = syllableGameState.currentQuestion.syllables.join(' • ');
 
     if (isCorrect) {
         syllableGameState.score += syllableGameState.currentQuestion.difficulty * 10;
@@ -72,80 +71,6 @@ function checkSyllableAnswer(userAnswer) {
     setTimeout(loadNextSyllableQuestion, 2500);
 }
 
-function splitIntoSyllables(word, syllableCount) {
-    // Simple heuristic syllable splitting for German
-    const vowels = 'aeiouäöüyAEIOUÄÖÜY';
-    const syllables = [];
-    let currentSyllable = '';
-    let vowelCount = 0;
-
-    for (let i = 0; i < word.length; i++) {
-        const char = word[i];
-        const isVowel = vowels.includes(char);
-
-        // Check for diphthongs (ei, au, eu, etc.)
-        const isDiphthong = i < word.length - 1 &&
-            vowels.includes(char) &&
-            vowels.includes(word[i + 1]) &&
-            (char.toLowerCase() + word[i + 1].toLowerCase()).match(/ei|ai|au|eu|äu|ie|oi|ui/);
-
-        currentSyllable += char;
-
-        if (isVowel) {
-            vowelCount++;
-
-            // If we've found enough vowels and there's more word left
-            if (vowelCount < syllableCount && i < word.length - 1) {
-                // Look ahead to find a good break point
-                let breakPoint = 0;
-                for (let j = i + 1; j < word.length; j++) {
-                    if (vowels.includes(word[j])) {
-                        breakPoint = j;
-                        break;
-                    }
-                }
-
-                if (breakPoint > 0) {
-                    // Include consonants before next vowel
-                    const consonantsBetween = breakPoint - i - 1;
-                    if (consonantsBetween > 0) {
-                        currentSyllable += word.substring(i + 1, i + 1 + Math.floor(consonantsBetween / 2));
-                        syllables.push(currentSyllable);
-                        currentSyllable = word.substring(i + 1 + Math.floor(consonantsBetween / 2), breakPoint);
-                        i = breakPoint - 1;
-                    }
-                }
-            } else if (isDiphthong) {
-                currentSyllable += word[i + 1];
-                i++;
-            }
-        }
-    }
-
-    if (currentSyllable) {
-        syllables.push(currentSyllable);
-    }
-
-    // If heuristic didn't work well, fall back to simple splitting
-    if (syllables.length !== syllableCount) {
-        return simpleSplit(word, syllableCount);
-    }
-
-    return syllables;
-}
-
-function simpleSplit(word, count) {
-    // Fallback: just split evenly
-    if (count === 1) return [word];
-
-    const chunkSize = Math.ceil(word.length / count);
-    const result = [];
-    for (let i = 0; i < word.length; i += chunkSize) {
-        result.push(word.substring(i, i + chunkSize));
-    }
-    return result.slice(0, count);
-}
-
 function updateSyllableUI() {
     document.getElementById('syllableScore').textContent = syllableGameState.score;
     document.getElementById('syllableCorrect').textContent = syllableGameState.correct;
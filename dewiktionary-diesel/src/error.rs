@@ -0,0 +1,61 @@
+//! Why a database call failed, so a long import can skip a bad row instead of stopping.
+
+use diesel::result::{ConnectionError, DatabaseErrorKind};
+use std::fmt;
+
+#[derive(Debug)]
+pub enum Error {
+    /// The database could not be opened.
+    Connection(ConnectionError),
+    /// A row breaks a constraint of the schema, e.g. a duplicate primary key.
+    ConstraintViolation(diesel::result::Error),
+    /// Any other database error.
+    Database(diesel::result::Error),
+}
+
+impl Error {
+    /// Whether skipping the row or storing it differently may help.
+    pub fn is_constraint_violation(&self) -> bool {
+        matches!(self, Self::ConstraintViolation(_))
+    }
+}
+
+impl From<ConnectionError> for Error {
+    fn from(error: ConnectionError) -> Self {
+        Self::Connection(error)
+    }
+}
+
+impl From<diesel::result::Error> for Error {
+    fn from(error: diesel::result::Error) -> Self {
+        match &error {
+            diesel::result::Error::DatabaseError(
+                DatabaseErrorKind::UniqueViolation
+                | DatabaseErrorKind::ForeignKeyViolation
+                | DatabaseErrorKind::NotNullViolation
+                | DatabaseErrorKind::CheckViolation,
+                _,
+            ) => Self::ConstraintViolation(error),
+            _ => Self::Database(error),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Connection(error) => write!(f, "connection failed: {error}"),
+            Self::ConstraintViolation(error) => write!(f, "constraint violated: {error}"),
+            Self::Database(error) => write!(f, "database error: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Connection(error) => Some(error),
+            Self::ConstraintViolation(error) | Self::Database(error) => Some(error),
+        }
+    }
+}
@@ -1,44 +1,437 @@
 use diesel::prelude::*;
 use diesel::sqlite::SqliteConnection;
-use schema::derdiedas;
+use schema::{
+    adjektiv, adjektiv_form, derdiedas, derdiedas_audio, derdiedas_bedeutung, derdiedas_beispiel,
+    derdiedas_bestandteil, derdiedas_beziehung, derdiedas_form, derdiedas_genus,
+    derdiedas_lautschrift, derdiedas_reim, derdiedas_uebersetzung, derdiedas_worttrennung, verb,
+    verb_form,
+};
 
+pub mod error;
 pub mod models;
 pub mod schema;
 
-pub fn establish_connection(database_url: &str) -> SqliteConnection {
-    SqliteConnection::establish(database_url)
-        .unwrap_or_else(|_| panic!("Error connecting to {}", database_url))
+pub use error::Error;
+
+pub fn establish_connection(database_url: &str) -> Result<SqliteConnection, Error> {
+    Ok(SqliteConnection::establish(database_url)?)
 }
 
-use crate::models::NewEntry;
+use crate::models::{
+    NewAdjektiv, NewAdjektivForm, NewAudio, NewBedeutung, NewBeispiel, NewBestandteil,
+    NewBeziehung, NewEntry, NewForm, NewGenus, NewLautschrift, NewReim, NewUebersetzung, NewVerb,
+    NewVerbForm, NewWorttrennung,
+};
 
-#[allow(clippy::too_many_arguments)]
-pub fn create_entry(
+/// Stores a noun and returns the id of the new entry. Homonyms get an entry each, but a second
+/// entry from the same section of the same page breaks a unique constraint.
+pub fn create_entry(conn: &mut SqliteConnection, entry: &NewEntry) -> Result<i32, Error> {
+    Ok(diesel::insert_into(derdiedas::table)
+        .values(entry)
+        .returning(derdiedas::id)
+        .get_result(conn)?)
+}
+
+/// Stores a noun like [`create_entry`], but replaces the entry from the same section of the
+/// same page, e.g. when a dump is imported again. Homonyms like the two "die Bank" come from
+/// different sections and keep their entries. The forms, meanings and everything else stored
+/// for the old entry are deleted, so they can be stored again for the returned id. Entries
+/// without a page are always added.
+pub fn upsert_entry(conn: &mut SqliteConnection, entry: &NewEntry) -> Result<i32, Error> {
+    conn.transaction(|conn| {
+        let id = derdiedas::table
+            .filter(derdiedas::titel.eq(entry.titel))
+            .filter(derdiedas::abschnitt.eq(entry.abschnitt))
+            .select(derdiedas::id)
+            .first::<i32>(conn)
+            .optional()?;
+        let Some(id) = id else {
+            return create_entry(conn, entry);
+        };
+        diesel::update(derdiedas::table.find(id))
+            .set(entry)
+            .execute(conn)?;
+        delete_details(conn, id)?;
+        Ok(id)
+    })
+}
+
+/// Deletes the rows of every table that belongs to an entry, but not the entry itself.
+fn delete_details(conn: &mut SqliteConnection, id: i32) -> QueryResult<()> {
+    diesel::delete(derdiedas_genus::table.filter(derdiedas_genus::derdiedas_id.eq(id)))
+        .execute(conn)?;
+    diesel::delete(derdiedas_form::table.filter(derdiedas_form::derdiedas_id.eq(id)))
+        .execute(conn)?;
+    diesel::delete(
+        derdiedas_worttrennung::table.filter(derdiedas_worttrennung::derdiedas_id.eq(id)),
+    )
+    .execute(conn)?;
+    diesel::delete(derdiedas_lautschrift::table.filter(derdiedas_lautschrift::derdiedas_id.eq(id)))
+        .execute(conn)?;
+    diesel::delete(derdiedas_audio::table.filter(derdiedas_audio::derdiedas_id.eq(id)))
+        .execute(conn)?;
+    diesel::delete(derdiedas_reim::table.filter(derdiedas_reim::derdiedas_id.eq(id)))
+        .execute(conn)?;
+    diesel::delete(derdiedas_bedeutung::table.filter(derdiedas_bedeutung::derdiedas_id.eq(id)))
+        .execute(conn)?;
+    diesel::delete(derdiedas_beispiel::table.filter(derdiedas_beispiel::derdiedas_id.eq(id)))
+        .execute(conn)?;
+    diesel::delete(
+        derdiedas_uebersetzung::table.filter(derdiedas_uebersetzung::derdiedas_id.eq(id)),
+    )
+    .execute(conn)?;
+    diesel::delete(derdiedas_bestandteil::table.filter(derdiedas_bestandteil::derdiedas_id.eq(id)))
+        .execute(conn)?;
+    diesel::delete(derdiedas_beziehung::table.filter(derdiedas_beziehung::derdiedas_id.eq(id)))
+        .execute(conn)?;
+    Ok(())
+}
+
+/// Stores one of the genders of a noun. Nouns like "Joghurt" have more than one.
+pub fn create_genus(
     conn: &mut SqliteConnection,
-    nominativ_singular: &str,
+    derdiedas_id: i32,
     genus: &str,
-    nominativ_plural: Option<&str>,
-    genitiv_singular: Option<&str>,
-    genitiv_plural: Option<&str>,
-    dativ_singular: Option<&str>,
-    dativ_plural: Option<&str>,
-    akkusativ_singular: Option<&str>,
-    akkusativ_plural: Option<&str>,
-) {
-    let new_post = NewEntry {
-        nominativ_singular,
+) -> Result<(), Error> {
+    let new_genus = NewGenus {
+        derdiedas_id,
         genus,
-        nominativ_plural,
-        genitiv_singular,
-        genitiv_plural,
-        dativ_singular,
-        dativ_plural,
-        akkusativ_singular,
-        akkusativ_plural,
-    };
-
-    diesel::insert_into(derdiedas::table)
-        .values(&new_post)
-        .execute(conn)
-        .expect("Error saving new entry");
+    };
+
+    diesel::insert_into(derdiedas_genus::table)
+        .values(&new_genus)
+        .execute(conn)?;
+    Ok(())
+}
+
+/// Stores the `nummer`-th form of a case, e.g. the second nominative plural "Worte" of "Wort".
+pub fn create_form(
+    conn: &mut SqliteConnection,
+    derdiedas_id: i32,
+    kasus: &str,
+    numerus: &str,
+    nummer: i32,
+    form: &str,
+) -> Result<(), Error> {
+    let new_form = NewForm {
+        derdiedas_id,
+        kasus,
+        numerus,
+        nummer,
+        form,
+    };
+
+    diesel::insert_into(derdiedas_form::table)
+        .values(&new_form)
+        .execute(conn)?;
+    Ok(())
+}
+
+/// Stores the syllables of the `nummer`-th singular or plural form, joined with "·", e.g.
+/// "Kopf·nüs·se". `berechnet` marks syllables that do not come from the dictionary.
+pub fn create_worttrennung(
+    conn: &mut SqliteConnection,
+    derdiedas_id: i32,
+    numerus: &str,
+    nummer: i32,
+    silben: &str,
+    berechnet: bool,
+) -> Result<(), Error> {
+    let new_worttrennung = NewWorttrennung {
+        derdiedas_id,
+        numerus,
+        nummer,
+        silben,
+        berechnet,
+    };
+
+    diesel::insert_into(derdiedas_worttrennung::table)
+        .values(&new_worttrennung)
+        .execute(conn)?;
+    Ok(())
+}
+
+/// Stores the IPA of the `nummer`-th singular or plural form, e.g. "ˈkɔp͡fˌnʊs".
+pub fn create_lautschrift(
+    conn: &mut SqliteConnection,
+    derdiedas_id: i32,
+    numerus: &str,
+    nummer: i32,
+    ipa: &str,
+) -> Result<(), Error> {
+    let new_lautschrift = NewLautschrift {
+        derdiedas_id,
+        numerus,
+        nummer,
+        ipa,
+    };
+
+    diesel::insert_into(derdiedas_lautschrift::table)
+        .values(&new_lautschrift)
+        .execute(conn)?;
+    Ok(())
+}
+
+/// Stores the audio file of the `nummer`-th singular or plural form, e.g. "De-Kopfnuss.ogg".
+pub fn create_audio(
+    conn: &mut SqliteConnection,
+    derdiedas_id: i32,
+    numerus: &str,
+    nummer: i32,
+    datei: &str,
+) -> Result<(), Error> {
+    let new_audio = NewAudio {
+        derdiedas_id,
+        numerus,
+        nummer,
+        datei,
+    };
+
+    diesel::insert_into(derdiedas_audio::table)
+        .values(&new_audio)
+        .execute(conn)?;
+    Ok(())
+}
+
+/// Stores a rhyme key of a noun, e.g. "ap͡fl̩" for "Apfel".
+pub fn create_reim(
+    conn: &mut SqliteConnection,
+    derdiedas_id: i32,
+    reim: &str,
+) -> Result<(), Error> {
+    let new_reim = NewReim { derdiedas_id, reim };
+
+    diesel::insert_into(derdiedas_reim::table)
+        .values(&new_reim)
+        .execute(conn)?;
+    Ok(())
+}
+
+/// Stores a numbered meaning of a noun, e.g. "2" and "Knochen, der die Zähne trägt".
+pub fn create_bedeutung(
+    conn: &mut SqliteConnection,
+    derdiedas_id: i32,
+    nummer: &str,
+    text: &str,
+) -> Result<(), Error> {
+    let new_bedeutung = NewBedeutung {
+        derdiedas_id,
+        nummer,
+        text,
+    };
+
+    diesel::insert_into(derdiedas_bedeutung::table)
+        .values(&new_bedeutung)
+        .execute(conn)?;
+    Ok(())
+}
+
+/// Stores the `nummer`-th example sentence of a noun. `maskiert` is the sentence with the
+/// article `artikel` replaced by "___".
+pub fn create_beispiel(
+    conn: &mut SqliteConnection,
+    derdiedas_id: i32,
+    nummer: i32,
+    bedeutungen: &str,
+    text: &str,
+    artikel: Option<&str>,
+    maskiert: Option<&str>,
+) -> Result<(), Error> {
+    let new_beispiel = NewBeispiel {
+        derdiedas_id,
+        nummer,
+        bedeutungen,
+        text,
+        artikel,
+        maskiert,
+    };
+
+    diesel::insert_into(derdiedas_beispiel::table)
+        .values(&new_beispiel)
+        .execute(conn)?;
+    Ok(())
+}
+
+/// Stores the `nummer`-th translation of a noun into the language `sprache`.
+pub fn create_uebersetzung(
+    conn: &mut SqliteConnection,
+    derdiedas_id: i32,
+    sprache: &str,
+    nummer: i32,
+    bedeutungen: &str,
+    text: &str,
+    umschrift: Option<&str>,
+) -> Result<(), Error> {
+    let new_uebersetzung = NewUebersetzung {
+        derdiedas_id,
+        sprache,
+        nummer,
+        bedeutungen,
+        text,
+        umschrift,
+    };
+
+    diesel::insert_into(derdiedas_uebersetzung::table)
+        .values(&new_uebersetzung)
+        .execute(conn)?;
+    Ok(())
+}
+
+/// Stores the `nummer`-th part of a compound and the linking element `fuge` after it.
+pub fn create_bestandteil(
+    conn: &mut SqliteConnection,
+    derdiedas_id: i32,
+    nummer: i32,
+    wort: &str,
+    fuge: Option<&str>,
+) -> Result<(), Error> {
+    let new_bestandteil = NewBestandteil {
+        derdiedas_id,
+        nummer,
+        wort,
+        fuge,
+    };
+
+    diesel::insert_into(derdiedas_bestandteil::table)
+        .values(&new_bestandteil)
+        .execute(conn)?;
+    Ok(())
+}
+
+/// Stores the `nummer`-th relation of a noun, e.g. the `art` "oberbegriff" with the `ziel`
+/// "Gewässer".
+pub fn create_beziehung(
+    conn: &mut SqliteConnection,
+    derdiedas_id: i32,
+    nummer: i32,
+    art: &str,
+    bedeutungen: &str,
+    ziel: &str,
+) -> Result<(), Error> {
+    let new_beziehung = NewBeziehung {
+        derdiedas_id,
+        nummer,
+        art,
+        bedeutungen,
+        ziel,
+    };
+
+    diesel::insert_into(derdiedas_beziehung::table)
+        .values(&new_beziehung)
+        .execute(conn)?;
+    Ok(())
+}
+
+/// Stores a verb with the first form of every parameter of its table and returns its id.
+#[allow(clippy::too_many_arguments)]
+pub fn create_verb(
+    conn: &mut SqliteConnection,
+    infinitiv: &str,
+    hilfsverb: Option<&str>,
+    praesens_ich: Option<&str>,
+    praesens_du: Option<&str>,
+    praesens_er_sie_es: Option<&str>,
+    praeteritum_ich: Option<&str>,
+    konjunktiv_ii_ich: Option<&str>,
+    partizip_ii: Option<&str>,
+    imperativ_singular: Option<&str>,
+    imperativ_plural: Option<&str>,
+) -> Result<i32, Error> {
+    let new_verb = NewVerb {
+        infinitiv,
+        hilfsverb,
+        praesens_ich,
+        praesens_du,
+        praesens_er_sie_es,
+        praeteritum_ich,
+        konjunktiv_ii_ich,
+        partizip_ii,
+        imperativ_singular,
+        imperativ_plural,
+    };
+
+    Ok(diesel::insert_into(verb::table)
+        .values(&new_verb)
+        .returning(verb::id)
+        .get_result(conn)?)
+}
+
+/// Stores the `nummer`-th text of a verb form, e.g. the second "hilfsverb" "haben" of
+/// "schwimmen".
+pub fn create_verbform(
+    conn: &mut SqliteConnection,
+    verb_id: i32,
+    form: &str,
+    nummer: i32,
+    text: &str,
+) -> Result<(), Error> {
+    let new_verbform = NewVerbForm {
+        verb_id,
+        form,
+        nummer,
+        text,
+    };
+
+    diesel::insert_into(verb_form::table)
+        .values(&new_verbform)
+        .execute(conn)?;
+    Ok(())
+}
+
+/// Stores an adjective with the first form of every degree and returns its id.
+pub fn create_adjektiv(
+    conn: &mut SqliteConnection,
+    positiv: &str,
+    komparativ: Option<&str>,
+    superlativ: Option<&str>,
+) -> Result<i32, Error> {
+    let new_adjektiv = NewAdjektiv {
+        positiv,
+        komparativ,
+        superlativ,
+    };
+
+    Ok(diesel::insert_into(adjektiv::table)
+        .values(&new_adjektiv)
+        .returning(adjektiv::id)
+        .get_result(conn)?)
+}
+
+/// Stores the `nummer`-th form of a degree, e.g. the second "komparativ" "gesunder" of
+/// "gesund".
+pub fn create_adjektivform(
+    conn: &mut SqliteConnection,
+    adjektiv_id: i32,
+    stufe: &str,
+    nummer: i32,
+    text: &str,
+) -> Result<(), Error> {
+    let new_adjektivform = NewAdjektivForm {
+        adjektiv_id,
+        stufe,
+        nummer,
+        text,
+    };
+
+    diesel::insert_into(adjektiv_form::table)
+        .values(&new_adjektivform)
+        .execute(conn)?;
+    Ok(())
+}
+
+/// Reads the nominative singular and the gender of every noun, e.g. to check how well the
+/// gender can be guessed.
+pub fn load_genera(conn: &mut SqliteConnection) -> Result<Vec<(String, String)>, Error> {
+    Ok(derdiedas::table
+        .select((derdiedas::nominativ_singular, derdiedas::genus))
+        .load(conn)?)
+}
+
+/// Reads the syllables of every form from the dictionary, joined with "·", e.g. to check
+/// computed syllables against them.
+pub fn load_worttrennungen(conn: &mut SqliteConnection) -> Result<Vec<String>, Error> {
+    Ok(derdiedas_worttrennung::table
+        .filter(derdiedas_worttrennung::berechnet.eq(false))
+        .select(derdiedas_worttrennung::silben)
+        .load(conn)?)
 }
@@ -1,11 +1,27 @@
-use crate::schema::derdiedas;
+use crate::schema::{
+    adjektiv, adjektiv_form, derdiedas, derdiedas_audio, derdiedas_bedeutung, derdiedas_beispiel,
+    derdiedas_bestandteil, derdiedas_beziehung, derdiedas_form, derdiedas_genus,
+    derdiedas_lautschrift, derdiedas_reim, derdiedas_uebersetzung, derdiedas_worttrennung, verb,
+    verb_form,
+};
+use dewiktionary::parser::{
+    DeutschSubstantivUebersicht, Form, Kasus, Numerus, Substantivart, Tantum,
+};
+use dewiktionary::plural::Pluralklasse;
 use diesel::prelude::*;
 
+/// The gender stored for pluralia tantum without one, like the `Genus=0` of Wiktionary.
+pub const OHNE_GENUS: &str = "0";
+
 #[derive(Queryable, Selectable)]
 #[diesel(table_name = derdiedas)]
 #[diesel(check_for_backend(diesel::sqlite::Sqlite))]
 pub struct DerDieDas {
+    pub id: i32,
+    /// The headword: the nominative singular, or the nominative plural of pluralia tantum
+    /// like "Leute".
     pub nominativ_singular: String,
+    /// "m", "f", "n" or [`OHNE_GENUS`].
     pub genus: String,
     pub nominativ_plural: Option<String>,
     pub genitiv_singular: Option<String>,
@@ -14,10 +30,64 @@ pub struct DerDieDas {
     pub dativ_plural: Option<String>,
     pub akkusativ_singular: Option<String>,
     pub akkusativ_plural: Option<String>,
+    pub art: String,
+    pub tantum: Option<String>,
+    pub pluralklasse: Option<String>,
+    /// The page of the dump the noun comes from.
+    pub titel: Option<String>,
+    /// The number of the noun section on the page, counted from 1.
+    pub abschnitt: Option<i32>,
 }
 
-#[derive(Insertable)]
+impl DerDieDas {
+    /// The form of the given case and number, e.g. to ask for a random case in a quiz.
+    pub fn form(&self, kasus: Kasus, numerus: Numerus) -> Option<&str> {
+        match (kasus, numerus) {
+            (Kasus::Nominativ, Numerus::Singular) => Some(self.nominativ_singular.as_str())
+                .filter(|_| self.tantum.as_deref() != Some(Tantum::Plural.name())),
+            (Kasus::Nominativ, Numerus::Plural) => self.nominativ_plural.as_deref(),
+            (Kasus::Genitiv, Numerus::Singular) => self.genitiv_singular.as_deref(),
+            (Kasus::Genitiv, Numerus::Plural) => self.genitiv_plural.as_deref(),
+            (Kasus::Dativ, Numerus::Singular) => self.dativ_singular.as_deref(),
+            (Kasus::Dativ, Numerus::Plural) => self.dativ_plural.as_deref(),
+            (Kasus::Akkusativ, Numerus::Singular) => self.akkusativ_singular.as_deref(),
+            (Kasus::Akkusativ, Numerus::Plural) => self.akkusativ_plural.as_deref(),
+        }
+    }
+}
+
+/// A stored noun with its first gender and the first form of every case.
+impl From<&DerDieDas> for DeutschSubstantivUebersicht {
+    fn from(eintrag: &DerDieDas) -> Self {
+        let mut formen = Vec::new();
+        for kasus in Kasus::ALLE {
+            for numerus in Numerus::ALLE {
+                if let Some(text) = eintrag.form(kasus, numerus) {
+                    formen.push(Form::new(kasus, numerus, text));
+                }
+            }
+        }
+        Self {
+            art: Substantivart::ALLE
+                .into_iter()
+                .find(|art| art.name() == eintrag.art)
+                .unwrap_or_default(),
+            tantum: Tantum::ALLE
+                .into_iter()
+                .find(|tantum| Some(tantum.name()) == eintrag.tantum.as_deref()),
+            genus: Some(eintrag.genus.clone())
+                .filter(|genus| genus != OHNE_GENUS)
+                .into_iter()
+                .collect(),
+            formen,
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Insertable, AsChangeset)]
 #[diesel(table_name = derdiedas)]
+#[diesel(treat_none_as_null = true)]
 pub struct NewEntry<'a> {
     pub nominativ_singular: &'a str,
     pub genus: &'a str,
@@ -28,4 +98,350 @@ pub struct NewEntry<'a> {
     pub dativ_plural: Option<&'a str>,
     pub akkusativ_singular: Option<&'a str>,
     pub akkusativ_plural: Option<&'a str>,
+    pub art: &'a str,
+    pub tantum: Option<&'a str>,
+    pub pluralklasse: Option<&'a str>,
+    pub titel: Option<&'a str>,
+    pub abschnitt: Option<i32>,
+}
+
+impl<'a> NewEntry<'a> {
+    /// The first gender and the first form of every case of a noun table. Pluralia tantum
+    /// like "Leute" get their nominative plural as the headword and [`OHNE_GENUS`] without a
+    /// gender. Other tables without a gender or a nominative singular give `None`. The page
+    /// and the number of the section are left for the caller to set.
+    pub fn from_uebersicht(t: &'a DeutschSubstantivUebersicht) -> Option<Self> {
+        let form = |kasus, numerus| t.form(kasus, numerus).first().copied();
+        let plurale_tantum = t.tantum == Some(Tantum::Plural);
+        let nominativ_plural = form(Kasus::Nominativ, Numerus::Plural);
+        let (nominativ_singular, genus) = if plurale_tantum {
+            let genus = t.genus.first().map_or(OHNE_GENUS, String::as_str);
+            (nominativ_plural?, genus)
+        } else {
+            (
+                form(Kasus::Nominativ, Numerus::Singular)?,
+                t.genus.first()?.as_str(),
+            )
+        };
+        Some(Self {
+            nominativ_singular,
+            genus,
+            nominativ_plural,
+            genitiv_singular: form(Kasus::Genitiv, Numerus::Singular),
+            genitiv_plural: form(Kasus::Genitiv, Numerus::Plural),
+            dativ_singular: form(Kasus::Dativ, Numerus::Singular),
+            dativ_plural: form(Kasus::Dativ, Numerus::Plural),
+            akkusativ_singular: form(Kasus::Akkusativ, Numerus::Singular),
+            akkusativ_plural: form(Kasus::Akkusativ, Numerus::Plural),
+            art: t.art.name(),
+            tantum: t.tantum.map(Tantum::name),
+            pluralklasse: nominativ_plural
+                .filter(|_| !plurale_tantum)
+                .and_then(|plural| Pluralklasse::bestimme(nominativ_singular, plural))
+                .map(Pluralklasse::name),
+            titel: None,
+            abschnitt: None,
+        })
+    }
+}
+
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = derdiedas_genus)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct DerDieDasGenus {
+    pub derdiedas_id: i32,
+    pub genus: String,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = derdiedas_genus)]
+pub struct NewGenus<'a> {
+    pub derdiedas_id: i32,
+    pub genus: &'a str,
+}
+
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = derdiedas_bestandteil)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct DerDieDasBestandteil {
+    pub derdiedas_id: i32,
+    pub nummer: i32,
+    pub wort: String,
+    pub fuge: Option<String>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = derdiedas_bestandteil)]
+pub struct NewBestandteil<'a> {
+    pub derdiedas_id: i32,
+    pub nummer: i32,
+    pub wort: &'a str,
+    pub fuge: Option<&'a str>,
+}
+
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = derdiedas_beziehung)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct DerDieDasBeziehung {
+    pub derdiedas_id: i32,
+    pub nummer: i32,
+    pub art: String,
+    pub bedeutungen: String,
+    pub ziel: String,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = derdiedas_beziehung)]
+pub struct NewBeziehung<'a> {
+    pub derdiedas_id: i32,
+    pub nummer: i32,
+    pub art: &'a str,
+    pub bedeutungen: &'a str,
+    pub ziel: &'a str,
+}
+
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = derdiedas_form)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct DerDieDasForm {
+    pub derdiedas_id: i32,
+    pub kasus: String,
+    pub numerus: String,
+    pub nummer: i32,
+    pub form: String,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = derdiedas_form)]
+pub struct NewForm<'a> {
+    pub derdiedas_id: i32,
+    pub kasus: &'a str,
+    pub numerus: &'a str,
+    pub nummer: i32,
+    pub form: &'a str,
+}
+
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = derdiedas_worttrennung)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct DerDieDasWorttrennung {
+    pub derdiedas_id: i32,
+    pub numerus: String,
+    pub nummer: i32,
+    pub silben: String,
+    pub berechnet: bool,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = derdiedas_worttrennung)]
+pub struct NewWorttrennung<'a> {
+    pub derdiedas_id: i32,
+    pub numerus: &'a str,
+    pub nummer: i32,
+    pub silben: &'a str,
+    pub berechnet: bool,
+}
+
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = derdiedas_lautschrift)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct DerDieDasLautschrift {
+    pub derdiedas_id: i32,
+    pub numerus: String,
+    pub nummer: i32,
+    pub ipa: String,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = derdiedas_lautschrift)]
+pub struct NewLautschrift<'a> {
+    pub derdiedas_id: i32,
+    pub numerus: &'a str,
+    pub nummer: i32,
+    pub ipa: &'a str,
+}
+
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = derdiedas_audio)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct DerDieDasAudio {
+    pub derdiedas_id: i32,
+    pub numerus: String,
+    pub nummer: i32,
+    pub datei: String,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = derdiedas_audio)]
+pub struct NewAudio<'a> {
+    pub derdiedas_id: i32,
+    pub numerus: &'a str,
+    pub nummer: i32,
+    pub datei: &'a str,
+}
+
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = derdiedas_reim)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct DerDieDasReim {
+    pub derdiedas_id: i32,
+    pub reim: String,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = derdiedas_reim)]
+pub struct NewReim<'a> {
+    pub derdiedas_id: i32,
+    pub reim: &'a str,
+}
+
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = derdiedas_bedeutung)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct DerDieDasBedeutung {
+    pub derdiedas_id: i32,
+    pub nummer: String,
+    pub text: String,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = derdiedas_bedeutung)]
+pub struct NewBedeutung<'a> {
+    pub derdiedas_id: i32,
+    pub nummer: &'a str,
+    pub text: &'a str,
+}
+
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = derdiedas_beispiel)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct DerDieDasBeispiel {
+    pub derdiedas_id: i32,
+    pub nummer: i32,
+    pub bedeutungen: String,
+    pub text: String,
+    pub artikel: Option<String>,
+    pub maskiert: Option<String>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = derdiedas_beispiel)]
+pub struct NewBeispiel<'a> {
+    pub derdiedas_id: i32,
+    pub nummer: i32,
+    pub bedeutungen: &'a str,
+    pub text: &'a str,
+    pub artikel: Option<&'a str>,
+    pub maskiert: Option<&'a str>,
+}
+
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = derdiedas_uebersetzung)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct DerDieDasUebersetzung {
+    pub derdiedas_id: i32,
+    pub sprache: String,
+    pub nummer: i32,
+    pub bedeutungen: String,
+    pub text: String,
+    pub umschrift: Option<String>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = derdiedas_uebersetzung)]
+pub struct NewUebersetzung<'a> {
+    pub derdiedas_id: i32,
+    pub sprache: &'a str,
+    pub nummer: i32,
+    pub bedeutungen: &'a str,
+    pub text: &'a str,
+    pub umschrift: Option<&'a str>,
+}
+
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = verb)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct Verb {
+    pub id: i32,
+    pub infinitiv: String,
+    pub hilfsverb: Option<String>,
+    pub praesens_ich: Option<String>,
+    pub praesens_du: Option<String>,
+    pub praesens_er_sie_es: Option<String>,
+    pub praeteritum_ich: Option<String>,
+    pub konjunktiv_ii_ich: Option<String>,
+    pub partizip_ii: Option<String>,
+    pub imperativ_singular: Option<String>,
+    pub imperativ_plural: Option<String>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = verb)]
+pub struct NewVerb<'a> {
+    pub infinitiv: &'a str,
+    pub hilfsverb: Option<&'a str>,
+    pub praesens_ich: Option<&'a str>,
+    pub praesens_du: Option<&'a str>,
+    pub praesens_er_sie_es: Option<&'a str>,
+    pub praeteritum_ich: Option<&'a str>,
+    pub konjunktiv_ii_ich: Option<&'a str>,
+    pub partizip_ii: Option<&'a str>,
+    pub imperativ_singular: Option<&'a str>,
+    pub imperativ_plural: Option<&'a str>,
+}
+
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = verb_form)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct VerbForm {
+    pub verb_id: i32,
+    pub form: String,
+    pub nummer: i32,
+    pub text: String,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = verb_form)]
+pub struct NewVerbForm<'a> {
+    pub verb_id: i32,
+    pub form: &'a str,
+    pub nummer: i32,
+    pub text: &'a str,
+}
+
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = adjektiv)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct Adjektiv {
+    pub id: i32,
+    pub positiv: String,
+    pub komparativ: Option<String>,
+    pub superlativ: Option<String>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = adjektiv)]
+pub struct NewAdjektiv<'a> {
+    pub positiv: &'a str,
+    pub komparativ: Option<&'a str>,
+    pub superlativ: Option<&'a str>,
+}
+
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = adjektiv_form)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct AdjektivForm {
+    pub adjektiv_id: i32,
+    pub stufe: String,
+    pub nummer: i32,
+    pub text: String,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = adjektiv_form)]
+pub struct NewAdjektivForm<'a> {
+    pub adjektiv_id: i32,
+    pub stufe: &'a str,
+    pub nummer: i32,
+    pub text: &'a str,
 }
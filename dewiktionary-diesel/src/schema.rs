@@ -1,7 +1,26 @@
 // @generated automatically by Diesel CLI.
 
 diesel::table! {
-    derdiedas (nominativ_singular) {
+    adjektiv (id) {
+        id -> Integer,
+        positiv -> Text,
+        komparativ -> Nullable<Text>,
+        superlativ -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    adjektiv_form (adjektiv_id, stufe, nummer) {
+        adjektiv_id -> Integer,
+        stufe -> Text,
+        nummer -> Integer,
+        text -> Text,
+    }
+}
+
+diesel::table! {
+    derdiedas (id) {
+        id -> Integer,
         nominativ_singular -> Text,
         genus -> Text,
         nominativ_plural -> Nullable<Text>,
@@ -11,5 +30,169 @@ diesel::table! {
         dativ_plural -> Nullable<Text>,
         akkusativ_singular -> Nullable<Text>,
         akkusativ_plural -> Nullable<Text>,
+        art -> Text,
+        tantum -> Nullable<Text>,
+        pluralklasse -> Nullable<Text>,
+        titel -> Nullable<Text>,
+        abschnitt -> Nullable<Integer>,
+    }
+}
+
+diesel::table! {
+    derdiedas_audio (derdiedas_id, numerus, nummer) {
+        derdiedas_id -> Integer,
+        numerus -> Text,
+        nummer -> Integer,
+        datei -> Text,
+    }
+}
+
+diesel::table! {
+    derdiedas_bedeutung (derdiedas_id, nummer) {
+        derdiedas_id -> Integer,
+        nummer -> Text,
+        text -> Text,
+    }
+}
+
+diesel::table! {
+    derdiedas_beispiel (derdiedas_id, nummer) {
+        derdiedas_id -> Integer,
+        nummer -> Integer,
+        bedeutungen -> Text,
+        text -> Text,
+        artikel -> Nullable<Text>,
+        maskiert -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    derdiedas_bestandteil (derdiedas_id, nummer) {
+        derdiedas_id -> Integer,
+        nummer -> Integer,
+        wort -> Text,
+        fuge -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    derdiedas_beziehung (derdiedas_id, nummer) {
+        derdiedas_id -> Integer,
+        nummer -> Integer,
+        art -> Text,
+        bedeutungen -> Text,
+        ziel -> Text,
+    }
+}
+
+diesel::table! {
+    derdiedas_form (derdiedas_id, kasus, numerus, nummer) {
+        derdiedas_id -> Integer,
+        kasus -> Text,
+        numerus -> Text,
+        nummer -> Integer,
+        form -> Text,
     }
 }
+
+diesel::table! {
+    derdiedas_genus (derdiedas_id, genus) {
+        derdiedas_id -> Integer,
+        genus -> Text,
+    }
+}
+
+diesel::table! {
+    derdiedas_lautschrift (derdiedas_id, numerus, nummer) {
+        derdiedas_id -> Integer,
+        numerus -> Text,
+        nummer -> Integer,
+        ipa -> Text,
+    }
+}
+
+diesel::table! {
+    derdiedas_reim (derdiedas_id, reim) {
+        derdiedas_id -> Integer,
+        reim -> Text,
+    }
+}
+
+diesel::table! {
+    derdiedas_uebersetzung (derdiedas_id, sprache, nummer) {
+        derdiedas_id -> Integer,
+        sprache -> Text,
+        nummer -> Integer,
+        bedeutungen -> Text,
+        text -> Text,
+        umschrift -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    derdiedas_worttrennung (derdiedas_id, numerus, nummer) {
+        derdiedas_id -> Integer,
+        numerus -> Text,
+        nummer -> Integer,
+        silben -> Text,
+        berechnet -> Bool,
+    }
+}
+
+diesel::table! {
+    verb (id) {
+        id -> Integer,
+        infinitiv -> Text,
+        hilfsverb -> Nullable<Text>,
+        praesens_ich -> Nullable<Text>,
+        praesens_du -> Nullable<Text>,
+        praesens_er_sie_es -> Nullable<Text>,
+        praeteritum_ich -> Nullable<Text>,
+        konjunktiv_ii_ich -> Nullable<Text>,
+        partizip_ii -> Nullable<Text>,
+        imperativ_singular -> Nullable<Text>,
+        imperativ_plural -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    verb_form (verb_id, form, nummer) {
+        verb_id -> Integer,
+        form -> Text,
+        nummer -> Integer,
+        text -> Text,
+    }
+}
+
+diesel::joinable!(adjektiv_form -> adjektiv (adjektiv_id));
+diesel::joinable!(derdiedas_audio -> derdiedas (derdiedas_id));
+diesel::joinable!(derdiedas_bedeutung -> derdiedas (derdiedas_id));
+diesel::joinable!(derdiedas_beispiel -> derdiedas (derdiedas_id));
+diesel::joinable!(derdiedas_bestandteil -> derdiedas (derdiedas_id));
+diesel::joinable!(derdiedas_beziehung -> derdiedas (derdiedas_id));
+diesel::joinable!(derdiedas_form -> derdiedas (derdiedas_id));
+diesel::joinable!(derdiedas_genus -> derdiedas (derdiedas_id));
+diesel::joinable!(derdiedas_lautschrift -> derdiedas (derdiedas_id));
+diesel::joinable!(derdiedas_reim -> derdiedas (derdiedas_id));
+diesel::joinable!(derdiedas_uebersetzung -> derdiedas (derdiedas_id));
+diesel::joinable!(derdiedas_worttrennung -> derdiedas (derdiedas_id));
+diesel::joinable!(verb_form -> verb (verb_id));
+
+diesel::allow_tables_to_appear_in_same_query!(
+    adjektiv,
+    adjektiv_form,
+    derdiedas,
+    derdiedas_audio,
+    derdiedas_bedeutung,
+    derdiedas_beispiel,
+    derdiedas_bestandteil,
+    derdiedas_beziehung,
+    derdiedas_form,
+    derdiedas_genus,
+    derdiedas_lautschrift,
+    derdiedas_reim,
+    derdiedas_uebersetzung,
+    derdiedas_worttrennung,
+    verb,
+    verb_form,
+);
@@ -1,9 +1,34 @@
 use clap::{crate_version, Parser};
-use dewiktionary::parser::DeutschSubstantivUebersicht;
-use dewiktionary_diesel::{create_entry, establish_connection};
+use dewiktionary::artikel::Genus;
+use dewiktionary::aussprache::Aussprache;
+use dewiktionary::bedeutung::Bedeutung;
+use dewiktionary::beispiel::Beispiel;
+use dewiktionary::beziehung::Beziehung;
+use dewiktionary::fehler::Parsefehler;
+use dewiktionary::herkunft::Zusammensetzung;
+use dewiktionary::parser::{
+    Adjektivabschnitt, DeutschAdjektivUebersicht, DeutschSubstantivUebersicht,
+    DeutschVerbUebersicht, Numerus, Substantivabschnitt, Tantum, Verbabschnitt,
+};
+use dewiktionary::silbentrennung::{vergleiche, Silbentrenner, Vergleich};
+use dewiktionary::uebersetzung::Uebersetzung;
+use dewiktionary::vorhersage::auswerten;
+use dewiktionary::worttrennung::Worttrennung;
+use dewiktionary_diesel::models::NewEntry;
+use dewiktionary_diesel::{
+    create_adjektiv, create_adjektivform, create_audio, create_bedeutung, create_beispiel,
+    create_bestandteil, create_beziehung, create_entry, create_form, create_genus,
+    create_lautschrift, create_reim, create_uebersetzung, create_verb, create_verbform,
+    create_worttrennung, establish_connection, load_genera, load_worttrennungen, upsert_entry,
+    Error,
+};
 use diesel::sqlite::SqliteConnection;
+use diesel::Connection;
 use dotenvy::dotenv;
-use tracing::{error, info};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::io::Write;
+use tracing::{error, info, warn};
 use tracing_subscriber::FmtSubscriber;
 
 extern crate bzip2;
@@ -22,8 +47,52 @@ struct Opts {
     filename: String,
 
     /// The path to the neo4j repository.
-    #[clap(short, long, env = "DATABASE_URL")]
-    database_url: String,
+    #[clap(short, long, env = "DATABASE_URL", required_unless_present = "jsonl")]
+    database_url: Option<String>,
+
+    /// Writes the parsed sections to this file as JSON Lines instead of inserting them into
+    /// the database.
+    #[clap(short, long)]
+    jsonl: Option<String>,
+
+    /// Writes the German noun sections that could not be imported to this file, one per line
+    /// with the page title, the byte offset, the field and the reason, separated by tabs.
+    #[clap(short, long)]
+    rejected: Option<String>,
+
+    /// Replaces nouns that are already in the database, found by page and noun section, instead
+    /// of skipping them, e.g. to import a newer dump.
+    #[clap(long)]
+    upsert: bool,
+
+    /// Instead of importing, splits every word with syllables in the database with the
+    /// hyphenation patterns and prints how many are split like in the dictionary.
+    #[clap(long)]
+    check_silben: bool,
+
+    /// Reads the hyphenation patterns from this file, one per line like the
+    /// `hyph-de-1996.pat.txt` of TeX, instead of using the few built-in rules.
+    #[clap(long)]
+    silbenmuster: Option<String>,
+
+    /// Instead of importing, guesses the gender of every noun in the database from its ending
+    /// or its last word and prints how many guesses are right.
+    #[clap(long)]
+    check_genus: bool,
+}
+
+/// Where the parsed sections go.
+enum Output {
+    Database(SqliteConnection),
+    Jsonl(Box<dyn Write>),
+}
+
+/// One line of the JSON Lines output.
+#[derive(Serialize)]
+struct JsonlLine<'a, T> {
+    titel: &'a str,
+    wortart: &'static str,
+    abschnitt: &'a T,
 }
 
 fn main() {
@@ -38,6 +107,48 @@ fn main() {
     tracing::subscriber::set_global_default(subscriber).expect("setting default subscriber failed");
     dotenv().ok();
     let opts: Opts = Opts::parse();
+    let silbenmuster = opts
+        .silbenmuster
+        .as_ref()
+        .map(|path| match std::fs::read_to_string(path) {
+            Err(error) => {
+                error!("Failed to read hyphenation patterns: {}", error);
+                std::process::exit(1);
+            }
+            Ok(text) => Silbentrenner::new(text.split_whitespace()),
+        });
+    let trenner = silbenmuster
+        .as_ref()
+        .unwrap_or_else(|| Silbentrenner::deutsch());
+    if opts.check_genus || opts.check_silben {
+        let Some(database_url) = &opts.database_url else {
+            error!("Checking needs a database URL");
+            std::process::exit(1);
+        };
+        let connection = &mut connect(database_url);
+        if opts.check_genus {
+            if let Err(error) = check_genus(connection) {
+                error!("Failed to load nouns: {}", error);
+                std::process::exit(1);
+            }
+        }
+        if opts.check_silben {
+            let vergleich = match check_silben(connection, trenner) {
+                Err(error) => {
+                    error!("Failed to load syllables: {}", error);
+                    std::process::exit(1);
+                }
+                Ok(vergleich) => vergleich,
+            };
+            info!(
+                "{} Wörter, {} richtig getrennt ({:.1} %)",
+                vergleich.gesamt,
+                vergleich.richtig,
+                100.0 * vergleich.richtig as f64 / vergleich.gesamt.max(1) as f64
+            );
+        }
+        return;
+    }
     info!("Starting dewiktionary-importer-cli {}", crate_version!());
     info!("Using file {}", opts.filename);
 
@@ -48,19 +159,99 @@ fn main() {
         }
         Ok(file) => std::io::BufReader::new(file),
     };
-    let connection = &mut establish_connection(&opts.database_url);
+    let mut rejected: Box<dyn Write> = match &opts.rejected {
+        None => Box::new(std::io::sink()),
+        Some(path) => match std::fs::File::create(path) {
+            Err(error) => {
+                error!("Failed to create report file: {}", error);
+                std::process::exit(1);
+            }
+            Ok(file) => Box::new(std::io::BufWriter::new(file)),
+        },
+    };
+    let mut output = match (&opts.jsonl, &opts.database_url) {
+        (Some(path), _) => match std::fs::File::create(path) {
+            Err(error) => {
+                error!("Failed to create JSON Lines file: {}", error);
+                std::process::exit(1);
+            }
+            Ok(file) => Output::Jsonl(Box::new(std::io::BufWriter::new(file))),
+        },
+        (None, Some(database_url)) => Output::Database(connect(database_url)),
+        (None, None) => unreachable!("clap requires a database URL without a JSON Lines file"),
+    };
     if opts.filename.ends_with(".bz2") {
         parse(
             std::io::BufReader::new(bzip2::bufread::MultiBzDecoder::new(file)),
-            connection,
+            &mut output,
+            &mut rejected,
+            opts.upsert,
+            trenner,
         );
     } else {
-        parse(file, connection);
+        parse(file, &mut output, &mut rejected, opts.upsert, trenner);
     }
+    if let Output::Jsonl(jsonl) = &mut output {
+        if let Err(error) = jsonl.flush() {
+            error!("Failed to write JSON Lines file: {}", error);
+            std::process::exit(1);
+        }
+    }
+    if let Err(error) = rejected.flush() {
+        error!("Failed to write report file: {}", error);
+    }
+}
+
+/// Guesses the gender of every noun in the database and logs how many guesses are right.
+fn check_genus(connection: &mut SqliteConnection) -> Result<(), Error> {
+    let woerter: Vec<(String, Genus)> = load_genera(connection)?
+        .into_iter()
+        .filter_map(|(wort, genus)| Some((wort, Genus::aus_kuerzel(&genus)?)))
+        .collect();
+    let auswertung = auswerten(&woerter);
+    info!(
+        "{} Substantive, {} vorhergesagt, {} richtig ({:.1} %)",
+        auswertung.gesamt,
+        auswertung.vorhergesagt,
+        auswertung.richtig,
+        100.0 * auswertung.richtig as f64 / auswertung.vorhergesagt.max(1) as f64
+    );
+    Ok(())
 }
 
-fn parse(source: impl std::io::BufRead, connection: &mut SqliteConnection) {
-    let _ = connection;
+/// Splits every word with dictionary syllables and counts how many are split the same way.
+fn check_silben(
+    connection: &mut SqliteConnection,
+    trenner: &Silbentrenner,
+) -> Result<Vergleich, Error> {
+    let worttrennungen: Vec<Vec<String>> = load_worttrennungen(connection)?
+        .iter()
+        .map(|silben| silben.split('·').map(str::to_string).collect())
+        .collect();
+    Ok(vergleiche(
+        trenner,
+        worttrennungen.iter().map(Vec::as_slice),
+    ))
+}
+
+/// Opens the database or stops the importer.
+fn connect(database_url: &str) -> SqliteConnection {
+    match establish_connection(database_url) {
+        Err(error) => {
+            error!("Failed to open database {}: {}", database_url, error);
+            std::process::exit(1);
+        }
+        Ok(connection) => connection,
+    }
+}
+
+fn parse(
+    source: impl std::io::BufRead,
+    output: &mut Output,
+    rejected: &mut dyn Write,
+    upsert: bool,
+    trenner: &Silbentrenner,
+) {
     let mut counter = 0;
     let mut gefundene_tabelle = 0;
     for result in parse_mediawiki_dump_reboot::parse(source) {
@@ -76,30 +267,76 @@ fn parse(source: impl std::io::BufRead, connection: &mut SqliteConnection) {
                     "Tabellen {} von {} Seiten. Aktuell: {}",
                     gefundene_tabelle, counter, page.title
                 );
-                let tabelle = DeutschSubstantivUebersicht::new(&page.text);
-                match tabelle {
-                    Some(t) => {
-                        info!("Substantivtabelle gefunden");
-                        info!("{:#?}", t);
-                        gefundene_tabelle += 1;
-                        create_entry(
+                // Rejected sections are counted as well, so a section keeps its number when
+                // the one before it is fixed in a newer dump.
+                let mut abschnitte = Vec::new();
+                for (nummer, ergebnis) in (1..).zip(Substantivabschnitt::lies(&page.text)) {
+                    match ergebnis {
+                        Ok(abschnitt) => abschnitte.push((nummer, abschnitt)),
+                        Err(fehler) => {
+                            warn!("Substantivtabelle abgelehnt: {}: {}", page.title, fehler);
+                            write_rejected(rejected, &page.title, &fehler);
+                        }
+                    }
+                }
+                for (nummer, abschnitt) in abschnitte {
+                    info!("Substantivtabelle gefunden: {}", abschnitt.ueberschrift);
+                    let gespeichert = match output {
+                        Output::Database(connection) => create_substantiv(
                             connection,
-                            &t.nominativ_singular.text,
-                            &t.genus.genus,
-                            Some(&t.nominativ_plural.text),
-                            Some(&t.genitiv_singular.text),
-                            Some(&t.genitiv_plural.text),
-                            Some(&t.dativ_singular.text),
-                            Some(&t.dativ_plural.text),
-                            Some(&t.akkusativ_singular.text),
-                            Some(&t.akkusativ_plural.text),
-                        );
+                            &page.title,
+                            nummer,
+                            abschnitt,
+                            upsert,
+                            trenner,
+                        ),
+                        Output::Jsonl(jsonl) => {
+                            write_jsonl(jsonl, &page.title, "substantiv", &abschnitt);
+                            true
+                        }
+                    };
+                    if gespeichert {
+                        gefundene_tabelle += 1;
                     }
-                    None => {
-                        //warn!("Keine Substantivtabelle gefunden");
-                        //for line in page.text.lines() {
-                        //    info!("{:#?}", line);
-                        //}
+                }
+                for abschnitt in Verbabschnitt::alle(&page.text) {
+                    info!("Verbtabelle gefunden: {}", abschnitt.ueberschrift);
+                    match output {
+                        Output::Database(connection) => {
+                            let gespeichert = connection.transaction(|connection| {
+                                create_verb_and_forms(
+                                    connection,
+                                    &abschnitt.infinitiv,
+                                    &abschnitt.uebersicht,
+                                )
+                            });
+                            if let Err(error) = gespeichert {
+                                warn!("Verb übersprungen: {}: {}", page.title, error);
+                            }
+                        }
+                        Output::Jsonl(jsonl) => {
+                            write_jsonl(jsonl, &page.title, "verb", &abschnitt);
+                        }
+                    }
+                }
+                for abschnitt in Adjektivabschnitt::alle(&page.text) {
+                    info!("Adjektivtabelle gefunden: {}", abschnitt.ueberschrift);
+                    match output {
+                        Output::Database(connection) => {
+                            let gespeichert = connection.transaction(|connection| {
+                                create_adjektiv_and_forms(
+                                    connection,
+                                    &abschnitt.wort,
+                                    &abschnitt.uebersicht,
+                                )
+                            });
+                            if let Err(error) = gespeichert {
+                                warn!("Adjektiv übersprungen: {}: {}", page.title, error);
+                            }
+                        }
+                        Output::Jsonl(jsonl) => {
+                            write_jsonl(jsonl, &page.title, "adjektiv", &abschnitt);
+                        }
                     }
                 }
                 //}
@@ -108,6 +345,345 @@ fn parse(source: impl std::io::BufRead, connection: &mut SqliteConnection) {
     }
 }
 
+/// Stores the `nummer`th noun section of a page with its forms, meanings and relations in one
+/// transaction, so a noun is stored completely or not at all. Pluralia tantum are stored under
+/// their nominative plural. Returns `false` for other sections without a nominative singular
+/// or a gender, and for sections the database refuses, which are logged and skipped, e.g. a
+/// section that is already stored without `upsert`.
+fn create_substantiv(
+    connection: &mut SqliteConnection,
+    title: &str,
+    nummer: i32,
+    abschnitt: Substantivabschnitt,
+    upsert: bool,
+    trenner: &Silbentrenner,
+) -> bool {
+    let t = abschnitt.uebersicht;
+    info!("{:#?}", t);
+    let Some(entry) = NewEntry::from_uebersicht(&t) else {
+        warn!(
+            "Substantivtabelle ohne Nominativ Singular oder Genus: {}",
+            title
+        );
+        return false;
+    };
+    let entry = NewEntry {
+        titel: Some(title),
+        abschnitt: Some(nummer),
+        ..entry
+    };
+    let gespeichert = connection.transaction(|connection| {
+        let id = if upsert {
+            upsert_entry(connection, &entry)?
+        } else {
+            create_entry(connection, &entry)?
+        };
+        create_genera_and_forms(connection, id, &t)?;
+        create_silben(connection, id, &entry, &abschnitt.worttrennung, trenner)?;
+        create_aussprache(connection, id, &abschnitt.aussprache)?;
+        create_bedeutungen(connection, id, &abschnitt.bedeutungen)?;
+        create_beispiele(connection, id, &t, &abschnitt.beispiele)?;
+        create_uebersetzungen(connection, id, &abschnitt.uebersetzungen)?;
+        if let Some(zusammensetzung) = &abschnitt.zusammensetzung {
+            create_bestandteile(connection, id, zusammensetzung)?;
+        }
+        create_beziehungen(connection, id, &abschnitt.beziehungen)
+    });
+    match gespeichert {
+        Ok(()) => true,
+        Err(error) if error.is_constraint_violation() => {
+            warn!("Substantiv übersprungen: {}: {}", title, error);
+            false
+        }
+        Err(error) => {
+            error!("Substantiv nicht gespeichert: {}: {}", title, error);
+            false
+        }
+    }
+}
+
+/// Appends a section to the JSON Lines output.
+fn write_jsonl<T: Serialize>(
+    jsonl: &mut dyn Write,
+    titel: &str,
+    wortart: &'static str,
+    abschnitt: &T,
+) {
+    let line = JsonlLine {
+        titel,
+        wortart,
+        abschnitt,
+    };
+    if let Err(error) = serde_json::to_writer(&mut *jsonl, &line)
+        .map_err(std::io::Error::from)
+        .and_then(|()| writeln!(jsonl))
+    {
+        error!("Failed to write JSON Lines file: {}", error);
+        std::process::exit(1);
+    }
+}
+
+/// Appends a line for a rejected noun section to the report.
+fn write_rejected(rejected: &mut dyn Write, title: &str, fehler: &Parsefehler) {
+    let offset = fehler.offset().map(|o| o.to_string()).unwrap_or_default();
+    if let Err(error) = writeln!(
+        rejected,
+        "{}\t{}\t{}\t{}",
+        title,
+        offset,
+        fehler.feld().unwrap_or_default(),
+        fehler
+    ) {
+        error!("Failed to write report file: {}", error);
+    }
+}
+
+/// Stores every gender and every numbered case form of a noun, so alternatives like
+/// "das Joghurt" or the plural "Worte" are not lost.
+fn create_genera_and_forms(
+    connection: &mut SqliteConnection,
+    id: i32,
+    t: &DeutschSubstantivUebersicht,
+) -> Result<(), Error> {
+    for (i, genus) in t.genus.iter().enumerate() {
+        if !t.genus[..i].contains(genus) {
+            create_genus(connection, id, genus)?;
+        }
+    }
+
+    for (kasus, numerus, texte) in t.alle_formen() {
+        for (nummer, form) in (1..).zip(texte) {
+            create_form(connection, id, kasus.name(), numerus.name(), nummer, form)?;
+        }
+    }
+    Ok(())
+}
+
+/// Stores a verb with the first form of every parameter, and every form in `verb_form`.
+fn create_verb_and_forms(
+    connection: &mut SqliteConnection,
+    infinitiv: &str,
+    t: &DeutschVerbUebersicht,
+) -> Result<(), Error> {
+    fn erste(formen: &[String]) -> Option<&str> {
+        formen.first().map(String::as_str)
+    }
+    let id = create_verb(
+        connection,
+        infinitiv,
+        erste(&t.hilfsverb),
+        erste(&t.praesens_ich),
+        erste(&t.praesens_du),
+        erste(&t.praesens_er_sie_es),
+        erste(&t.praeteritum_ich),
+        erste(&t.konjunktiv_ii_ich),
+        erste(&t.partizip_ii),
+        erste(&t.imperativ_singular),
+        erste(&t.imperativ_plural),
+    )?;
+    let formen = [
+        ("hilfsverb", &t.hilfsverb),
+        ("praesens_ich", &t.praesens_ich),
+        ("praesens_du", &t.praesens_du),
+        ("praesens_er_sie_es", &t.praesens_er_sie_es),
+        ("praeteritum_ich", &t.praeteritum_ich),
+        ("konjunktiv_ii_ich", &t.konjunktiv_ii_ich),
+        ("partizip_ii", &t.partizip_ii),
+        ("imperativ_singular", &t.imperativ_singular),
+        ("imperativ_plural", &t.imperativ_plural),
+    ];
+    for (form, texte) in formen {
+        for (nummer, text) in (1..).zip(texte) {
+            create_verbform(connection, id, form, nummer, text)?;
+        }
+    }
+    Ok(())
+}
+
+/// Stores an adjective with the first form of every degree, and every form in
+/// `adjektiv_form`. Tables without a positive use the word of the page.
+fn create_adjektiv_and_forms(
+    connection: &mut SqliteConnection,
+    wort: &str,
+    t: &DeutschAdjektivUebersicht,
+) -> Result<(), Error> {
+    let id = create_adjektiv(
+        connection,
+        t.positiv.first().map_or(wort, String::as_str),
+        t.komparativ.first().map(String::as_str),
+        t.superlativ.first().map(String::as_str),
+    )?;
+    let stufen = [
+        ("positiv", &t.positiv),
+        ("komparativ", &t.komparativ),
+        ("superlativ", &t.superlativ),
+    ];
+    for (stufe, texte) in stufen {
+        for (nummer, text) in (1..).zip(texte) {
+            create_adjektivform(connection, id, stufe, nummer, text)?;
+        }
+    }
+    Ok(())
+}
+
+/// Stores the syllables of every singular and plural form from the `{{Worttrennung}}` block.
+/// Without one, the syllables of the headword are computed.
+fn create_silben(
+    connection: &mut SqliteConnection,
+    id: i32,
+    entry: &NewEntry,
+    worttrennung: &Worttrennung,
+    trenner: &Silbentrenner,
+) -> Result<(), Error> {
+    if worttrennung.singular.is_empty() && worttrennung.plural.is_empty() {
+        let numerus = if entry.tantum == Some(Tantum::Plural.name()) {
+            Numerus::Plural
+        } else {
+            Numerus::Singular
+        };
+        let silben = trenner.trenne(entry.nominativ_singular);
+        return create_worttrennung(connection, id, numerus.name(), 1, &silben.join("·"), true);
+    }
+    let formen = [
+        ("singular", &worttrennung.singular),
+        ("plural", &worttrennung.plural),
+    ];
+    for (numerus, formen) in formen {
+        for (nummer, silben) in (1..).zip(formen) {
+            create_worttrennung(connection, id, numerus, nummer, &silben.join("·"), false)?;
+        }
+    }
+    Ok(())
+}
+
+/// Stores the IPA and audio files of every singular and plural form, and the rhyme keys.
+fn create_aussprache(
+    connection: &mut SqliteConnection,
+    id: i32,
+    aussprache: &Aussprache,
+) -> Result<(), Error> {
+    let lautschriften = [
+        ("singular", &aussprache.ipa_singular),
+        ("plural", &aussprache.ipa_plural),
+    ];
+    for (numerus, lautschriften) in lautschriften {
+        for (nummer, ipa) in (1..).zip(lautschriften) {
+            create_lautschrift(connection, id, numerus, nummer, ipa)?;
+        }
+    }
+
+    let audios = [
+        ("singular", &aussprache.audio_singular),
+        ("plural", &aussprache.audio_plural),
+    ];
+    for (numerus, audios) in audios {
+        for (nummer, datei) in (1..).zip(audios) {
+            create_audio(connection, id, numerus, nummer, datei)?;
+        }
+    }
+
+    for (i, reim) in aussprache.reime.iter().enumerate() {
+        if !aussprache.reime[..i].contains(reim) {
+            create_reim(connection, id, reim)?;
+        }
+    }
+    Ok(())
+}
+
+/// Stores the numbered meanings. A number that appears twice keeps its first meaning.
+fn create_bedeutungen(
+    connection: &mut SqliteConnection,
+    id: i32,
+    bedeutungen: &[Bedeutung],
+) -> Result<(), Error> {
+    for (i, bedeutung) in bedeutungen.iter().enumerate() {
+        if !bedeutungen[..i]
+            .iter()
+            .any(|b| b.nummer == bedeutung.nummer)
+        {
+            create_bedeutung(connection, id, &bedeutung.nummer, &bedeutung.text)?;
+        }
+    }
+    Ok(())
+}
+
+/// Stores the example sentences, each with the article of the noun masked if it has one.
+fn create_beispiele(
+    connection: &mut SqliteConnection,
+    id: i32,
+    t: &DeutschSubstantivUebersicht,
+    beispiele: &[Beispiel],
+) -> Result<(), Error> {
+    let formen: Vec<&str> = t.formen.iter().map(|f| f.text.as_str()).collect();
+    for (nummer, beispiel) in (1..).zip(beispiele) {
+        let maskierung = beispiel.maskiere(&formen);
+        create_beispiel(
+            connection,
+            id,
+            nummer,
+            &beispiel.bedeutungen.join(", "),
+            &beispiel.text,
+            maskierung.as_ref().map(|m| m.artikel.as_str()),
+            maskierung.as_ref().map(|m| m.text.as_str()),
+        )?;
+    }
+    Ok(())
+}
+
+/// Stores the parts of a compound, so the view `derdiedas_kompositum` links it to its head word.
+fn create_bestandteile(
+    connection: &mut SqliteConnection,
+    id: i32,
+    zusammensetzung: &Zusammensetzung,
+) -> Result<(), Error> {
+    for (nummer, teil) in (1..).zip(&zusammensetzung.teile) {
+        create_bestandteil(connection, id, nummer, &teil.wort, teil.fuge.as_deref())?;
+    }
+    Ok(())
+}
+
+/// Stores the relations to other lemmas, e.g. synonyms and hypernyms.
+fn create_beziehungen(
+    connection: &mut SqliteConnection,
+    id: i32,
+    beziehungen: &[Beziehung],
+) -> Result<(), Error> {
+    for (nummer, beziehung) in (1..).zip(beziehungen) {
+        create_beziehung(
+            connection,
+            id,
+            nummer,
+            beziehung.art.name(),
+            &beziehung.bedeutungen.join(", "),
+            &beziehung.ziel,
+        )?;
+    }
+    Ok(())
+}
+
+/// Stores the translations, numbered per language in the order of the tables.
+fn create_uebersetzungen(
+    connection: &mut SqliteConnection,
+    id: i32,
+    uebersetzungen: &[Uebersetzung],
+) -> Result<(), Error> {
+    let mut nummern: HashMap<&str, i32> = HashMap::new();
+    for uebersetzung in uebersetzungen {
+        let nummer = nummern.entry(&uebersetzung.sprache).or_insert(0);
+        *nummer += 1;
+        create_uebersetzung(
+            connection,
+            id,
+            &uebersetzung.sprache,
+            *nummer,
+            &uebersetzung.bedeutungen.join(", "),
+            &uebersetzung.text,
+            uebersetzung.umschrift.as_deref(),
+        )?;
+    }
+    Ok(())
+}
+
 // Page {
 //     format: Some(
 //         "text/x-wiki",
@@ -121,3 +697,228 @@ fn parse(source: impl std::io::BufRead, connection: &mut SqliteConnection) {
 //                {{Deutsch Substantiv Übersicht\n|Genus=f\n|Nominativ Singular=Kopfnuss\n|Nominativ Plural=Kopfnüsse\n|Genitiv Singular=Kopfnuss\n|Genitiv Plural=Kopfnüsse\n|Dativ Singular=Kopfnuss\n|Dativ Plural=Kopfnüssen\n|Akkusativ Singular=Kopfnuss\n|Akkusativ Plural=Kopfnüsse\n}}\n\n{{Nicht mehr gültige Schreibweisen}}\n:[[Kopfnuß]]\n\n{{Worttrennung}}\n:Kopf·nuss, {{Pl.}} Kopf·nüs·se\n\n{{Aussprache}}\n:{{IPA}} {{Lautschrift|ˈkɔp\u{361}fˌnʊs}}\n:{{Hörbeispiele}} {{Audio|De-Kopfnuss.ogg}}\n\n{{Bedeutungen}}\n:[1] ein [[leicht]]er [[Schlag]] mit den [[Fingerknöchel]]n auf den [[Kopf]]\n:[2] eine [[Denksportaufgabe]]\n\n{{Herkunft}}\n:[[Determinativkompositum]] ([[Zusammensetzung]]) aus den [[Substantiv]]en ''[[Kopf]]'' und ''[[Nuss]]''\n\n{{Synonyme}}\n:[1] [[Katzenkopf]]\n\n{{Sinnverwandte Wörter}}\n:[1] [[Ohrfeige]]\n\n{{Beispiele}}\n:[1] Er gab mir eine ''Kopfnuss.''\n:[1] Im 19. Jahrhundert war es in Deutschland normal, dass Lehrer geschlagen oder ''Kopfnüsse'' verteilt haben.\n:[1] „»Herr Lehrer, als ich gestern Abend nach Hause kam, stritten sich unsere beiden Kinder. Meine Frau meckerte die beiden an, und ich hab dann auch noch rumgebrüllt, hab der einen sogar eine kleine ''Kopfnuss'' verpasst. Das hätte nicht passieren sollen, ich weiß.«“<ref>{{Per-Deutschlandradio | Online=https://www.deutschlandfunk.de/nachhilfe-fuer-vaeter.795.de.html?dram:article_id=116791 | Autor=Gunnar Köhne | Titel=Nachhilfe für Väter | TitelErg=Rollenbild in der Türkei im Wandel | Tag=27 | Monat=03 | Jahr=2007 | Zugriff=2019-01-08 | Kommentar=Deutschlandradio / Köln, Sendereihe: Europa heute }}</ref>\n:[1] „Es gab in Deutschland eine Zeit, da mussten alle an einem Tisch sitzen, wenn der Vater nach Hause kam, und schön langsam und brav essen, und man musste aufessen. Hat man das nicht getan, hat man eine Watschen oder eine ''Kopfnuss'' bekommen.“<ref>{{Per-Bayerischer Rundfunk | Online=https://www.br.de/fernsehen/ard-alpha/sendungen/alpha-forum/alexander-herrmann-sendung100.html | Autor= | Titel=Sternekoch – Herrmann, Alexander | TitelErg= | Tag=02 | Monat=08 | Jahr=2011 | Zugriff=2019-01-08 | Kommentar= }}</ref>\n:[1] Was das bedeutet, Ärger [im Alltag mit Kunden], das erklärt ihr Kollege Slobodan Trifkovic: „Ob’s körperlich ist, bespucken, oder …“ – „Beißen… Ja, beißen, oder ob man sich eine ''Kopfnuss'' einfängt.“<ref>{{Per-Deutschlandradio | Online=https://www.deutschlandfunk.de/angriffe-auf-behoerdenmitarbeiter-vorfaelle-werden-oft.1769.de.html?dram:article_id=350221 | Autor=Vivien Leue | Titel=Angriffe auf BehördenmitarbeiterVorfälle werden oft bagatellisiert | TitelErg= | Tag=04 | Monat=04 | Jahr=2016 | Zugriff=2019-01-08 | Kommentar=Detschlandfunk / Köln, Sendereihe: Deutschland heute }}</ref>\n:[2] Dieses Rätsel war schon eine ''Kopfnuss.'' \n\n==== {{Übersetzungen}} ====\n{{Ü-Tabelle|1|G=ein [[leicht]]er [[Schlag]] mit den [[Fingerknöchel]]n auf den [[Kopf]]|Ü-Liste=\n*{{en}}: {{Ü|en|}}\n*{{fr}}: [1] {{Ü|fr|tape sur la tête}} {{f}}; [2] {{Ü|fr|casse-tête}} {{m}}\n*{{sv}}: {{Ü|sv|tankenöt}}\n}}\n{{Ü-Tabelle|2|G=eine [[Denksportaufgabe]]|Ü-Liste=\n*{{en}}: {{Ü|en|brain teaser}}<ref>{{Wikipedia|Brain teaser|brain teaser|spr=en}}</ref>\n*{{sv}}: {{Ü|sv|}}\n}}\n\n{{Referenzen}}\n:[1, 2] {{Wikipedia|Kopfnuss}}\n:[1] {{Ref-DWDS|Kopfnuß}}\n:[*] {{Ref-UniLeipzig|Kopfnuss}}\n:[*] {{Ref-OWID|elexiko|210954|Kopfnuss}}\n:[1, 2] {{Ref-Pons|Kopfnuß}}\n:[1, 2] {{Ref-FreeDictionary|Kopfnuss}}\n:[1] Deutsche Welle, Deutsch lernen – Wort der Woche: {{Per-Deutsche Welle | Online=https://p.dw.com/p/18KnN | Autor=Hanna Grimm | Titel=Die Kopfnuss | TitelErg= | Tag=24 | Monat=06 | Jahr=2013 | Zugriff=2019-01-05 | Kommentar=Text und [https://www.dw.com/overlay/media/de/die-kopfnuss/16737550/16762901 Audio zum Download], Dauer 01:25 mm:ss }}\n\n{{Quellen}}\n\n{{Ähnlichkeiten 1|[[Kopfschuss]]}}",
 //     title: "Kopfnuss",
 // }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use diesel::connection::SimpleConnection;
+
+    /// An empty database in memory with every migration.
+    fn datenbank() -> SqliteConnection {
+        let mut connection = establish_connection(":memory:").unwrap();
+        let ordner = concat!(env!("CARGO_MANIFEST_DIR"), "/../migrations");
+        let mut migrationen: Vec<_> = std::fs::read_dir(ordner)
+            .unwrap()
+            .map(|eintrag| eintrag.unwrap().path().join("up.sql"))
+            .filter(|migration| migration.exists())
+            .collect();
+        migrationen.sort();
+        for migration in migrationen {
+            let sql = std::fs::read_to_string(&migration).unwrap();
+            connection.batch_execute(&sql).unwrap();
+        }
+        connection
+    }
+
+    /// Imports pages like a dump would hold them into the database.
+    fn importiere(connection: SqliteConnection, seiten: &[&str], upsert: bool) -> SqliteConnection {
+        let mut dump =
+            String::from("<mediawiki xmlns=\"http://www.mediawiki.org/xml/export-0.10/\">");
+        for (id, text) in (1..).zip(seiten) {
+            let titel = text.split(" ({{").next().unwrap().trim_start_matches("== ");
+            let text = text.replace('&', "&amp;").replace('<', "&lt;");
+            dump.push_str(&format!(
+                "<page><title>{titel}</title><ns>0</ns><id>{id}</id><revision><id>{id}</id>\
+                 <model>wikitext</model><format>text/x-wiki</format>\
+                 <text xml:space=\"preserve\">{text}</text></revision></page>"
+            ));
+        }
+        dump.push_str("</mediawiki>");
+        let mut output = Output::Database(connection);
+        parse(
+            dump.as_bytes(),
+            &mut output,
+            &mut std::io::sink(),
+            upsert,
+            Silbentrenner::deutsch(),
+        );
+        match output {
+            Output::Database(connection) => connection,
+            Output::Jsonl(_) => unreachable!(),
+        }
+    }
+
+    const APFEL: &str = include_str!("../../dewiktionary/tests/snippets/apfel.wiki");
+    const JOGHURT: &str = include_str!("../../dewiktionary/tests/snippets/joghurt.wiki");
+    const KOPFNUSS: &str = include_str!("../../dewiktionary/tests/snippets/kopfnuss.wiki");
+    const SCHWARZES_BRETT: &str =
+        include_str!("../../dewiktionary/tests/snippets/schwarzes-brett.wiki");
+    const LEUTE: &str = "== Leute ({{Sprache|Deutsch}}) ==
+=== {{Wortart|Substantiv|Deutsch}}, {{Pl.}} ===
+
+{{Deutsch Substantiv Übersicht
+|Genus=0
+|Nominativ Singular=—
+|Nominativ Plural=Leute
+|Genitiv Singular=—
+|Genitiv Plural=Leute
+|Dativ Singular=—
+|Dativ Plural=Leuten
+|Akkusativ Singular=—
+|Akkusativ Plural=Leute
+}}
+
+{{Worttrennung}}
+:{{Pl.}} Leu·te
+";
+    const BANK: &str = "== Bank ({{Sprache|Deutsch}}) ==
+=== {{Wortart|Substantiv|Deutsch}}, {{f}} ===
+
+{{Deutsch Substantiv Übersicht
+|Genus=f
+|Nominativ Singular=Bank
+|Nominativ Plural=Bänke
+|Genitiv Singular=Bank
+|Genitiv Plural=Bänke
+|Dativ Singular=Bank
+|Dativ Plural=Bänken
+|Akkusativ Singular=Bank
+|Akkusativ Plural=Bänke
+}}
+
+{{Bedeutungen}}
+:[1] Sitzgelegenheit für mehrere Personen
+
+=== {{Wortart|Substantiv|Deutsch}}, {{f}} ===
+
+{{Deutsch Substantiv Übersicht
+|Genus=f
+|Nominativ Singular=Bank
+|Nominativ Plural=Banken
+|Genitiv Singular=Bank
+|Genitiv Plural=Banken
+|Dativ Singular=Bank
+|Dativ Plural=Banken
+|Akkusativ Singular=Bank
+|Akkusativ Plural=Banken
+}}
+
+{{Bedeutungen}}
+:[1] Unternehmen, das mit Geld handelt
+";
+
+    #[test]
+    fn plurale_tantum() {
+        use dewiktionary::parser::Kasus;
+        use dewiktionary_diesel::models::DerDieDas;
+        use dewiktionary_diesel::schema::{derdiedas, derdiedas_worttrennung};
+        use diesel::prelude::*;
+
+        let mut connection = importiere(datenbank(), &[LEUTE], false);
+        let leute: DerDieDas = derdiedas::table
+            .select(DerDieDas::as_select())
+            .first(&mut connection)
+            .unwrap();
+        assert_eq!(leute.nominativ_singular, "Leute");
+        assert_eq!(leute.genus, "0");
+        assert_eq!(leute.tantum.as_deref(), Some("plural"));
+        assert_eq!(leute.dativ_plural.as_deref(), Some("Leuten"));
+        assert_eq!(leute.pluralklasse, None);
+        assert_eq!(leute.form(Kasus::Nominativ, Numerus::Singular), None);
+        let silben: Vec<(String, String)> = derdiedas_worttrennung::table
+            .select((
+                derdiedas_worttrennung::numerus,
+                derdiedas_worttrennung::silben,
+            ))
+            .load(&mut connection)
+            .unwrap();
+        assert_eq!(silben, [("plural".to_string(), "Leu·te".to_string())]);
+    }
+
+    #[test]
+    fn zweimal_importieren() {
+        use dewiktionary_diesel::schema::{derdiedas, derdiedas_bedeutung};
+        use diesel::prelude::*;
+
+        fn plurale(connection: &mut SqliteConnection) -> Vec<(i32, Option<String>)> {
+            derdiedas::table
+                .select((derdiedas::id, derdiedas::nominativ_plural))
+                .order(derdiedas::abschnitt)
+                .load(connection)
+                .unwrap()
+        }
+
+        // The two "die Bank" share word, gender and table, but not their section.
+        let mut connection = importiere(datenbank(), &[BANK], false);
+        let vorher = plurale(&mut connection);
+        assert_eq!(
+            vorher
+                .iter()
+                .map(|(_, plural)| plural.as_deref())
+                .collect::<Vec<_>>(),
+            [Some("Bänke"), Some("Banken")]
+        );
+        let mut connection = importiere(connection, &[BANK], false);
+        assert_eq!(plurale(&mut connection), vorher);
+        let mut connection = importiere(connection, &[BANK], true);
+        assert_eq!(plurale(&mut connection), vorher);
+        let bedeutungen: Vec<(i32, String)> = derdiedas_bedeutung::table
+            .select((derdiedas_bedeutung::derdiedas_id, derdiedas_bedeutung::text))
+            .order(derdiedas_bedeutung::derdiedas_id)
+            .load(&mut connection)
+            .unwrap();
+        assert_eq!(
+            bedeutungen,
+            [
+                (
+                    vorher[0].0,
+                    "Sitzgelegenheit für mehrere Personen".to_string()
+                ),
+                (vorher[1].0, "Unternehmen, das mit Geld handelt".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn transaktion() {
+        use dewiktionary_diesel::schema::{derdiedas, derdiedas_form};
+        use diesel::prelude::*;
+
+        let mut connection = datenbank();
+        connection
+            .batch_execute(
+                "CREATE TRIGGER kaputt BEFORE INSERT ON derdiedas_reim
+                 BEGIN SELECT RAISE(ABORT, 'kaputt'); END;",
+            )
+            .unwrap();
+        // The rhyme of "Apfel" cannot be stored, so nothing of "Apfel" is.
+        let mut connection = importiere(connection, &[APFEL, KOPFNUSS], false);
+        let woerter: Vec<String> = derdiedas::table
+            .select(derdiedas::nominativ_singular)
+            .load(&mut connection)
+            .unwrap();
+        assert_eq!(woerter, ["Kopfnuss"]);
+        let formen: i64 = derdiedas_form::table
+            .count()
+            .get_result(&mut connection)
+            .unwrap();
+        assert_eq!(formen, 8);
+    }
+
+    #[test]
+    fn silben_pruefen() {
+        let seiten = [APFEL, JOGHURT, KOPFNUSS, SCHWARZES_BRETT];
+        let mut connection = importiere(datenbank(), &seiten, false);
+        // "Ap·fel", "Äp·fel", "Kopf·nuss" and "Kopf·nüs·se" are split right, but "Jo·ghurt"
+        // and "Jo·ghurts" are not. The computed syllables of "Schwarzes Brett" do not count.
+        assert_eq!(
+            check_silben(&mut connection, Silbentrenner::deutsch()).unwrap(),
+            Vergleich {
+                gesamt: 7,
+                richtig: 4
+            }
+        );
+        let trenner = Silbentrenner::new(["1gh", "g2h", "o3gh"]);
+        assert_eq!(check_silben(&mut connection, &trenner).unwrap().richtig, 3);
+    }
+}
@@ -0,0 +1,248 @@
+//! The articles of a noun for every gender, case and number, e.g. "der", "keinem" or
+//! "unseren", and the contractions of a preposition with the definite article like "zum".
+
+use crate::parser::{Kasus, Numerus};
+
+/// The gender of a noun.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Genus {
+    Maskulinum,
+    Femininum,
+    Neutrum,
+}
+
+impl Genus {
+    pub const ALLE: [Self; 3] = [Self::Maskulinum, Self::Femininum, Self::Neutrum];
+
+    /// `m`, `f` or `n`, like in `|Genus=m` and in the `genus` column of the database.
+    pub fn kuerzel(self) -> &'static str {
+        match self {
+            Self::Maskulinum => "m",
+            Self::Femininum => "f",
+            Self::Neutrum => "n",
+        }
+    }
+
+    pub fn aus_kuerzel(kuerzel: &str) -> Option<Self> {
+        Self::ALLE.into_iter().find(|g| g.kuerzel() == kuerzel)
+    }
+
+    /// The gender a definite article in the nominative singular stands for, e.g. `Die`.
+    pub fn aus_artikel(artikel: &str) -> Option<Self> {
+        Self::ALLE.into_iter().find(|&genus| {
+            artikel.eq_ignore_ascii_case(bestimmt(genus, Kasus::Nominativ, Numerus::Singular))
+        })
+    }
+}
+
+/// Whose thing a possessive article names, from "mein" to "euer". "ihr" stands for "her"
+/// and for "their".
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Besitzer {
+    Mein,
+    Dein,
+    Sein,
+    Ihr,
+    Unser,
+    Euer,
+}
+
+impl Besitzer {
+    /// The article without an ending, as in "mein Hund".
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Mein => "mein",
+            Self::Dein => "dein",
+            Self::Sein => "sein",
+            Self::Ihr => "ihr",
+            Self::Unser => "unser",
+            Self::Euer => "euer",
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Artikelart {
+    /// "der", "die", "das".
+    Bestimmt,
+    /// "ein", "eine".
+    Unbestimmt,
+    /// "kein", "keine".
+    Negativ,
+    /// "mein", "deine", "unseren", ….
+    Possessiv(Besitzer),
+}
+
+/// The contractions of a preposition with a definite article.
+pub const VERSCHMELZUNGEN: [(&str, &str, &str); 8] = [
+    ("an", "dem", "am"),
+    ("an", "das", "ans"),
+    ("bei", "dem", "beim"),
+    ("in", "dem", "im"),
+    ("in", "das", "ins"),
+    ("von", "dem", "vom"),
+    ("zu", "dem", "zum"),
+    ("zu", "der", "zur"),
+];
+
+/// The article of the given kind for a noun, e.g. "dem" for the dative singular of a
+/// masculine noun. The indefinite article has no plural, "ein Apfel" becomes "Äpfel".
+pub fn artikel(art: Artikelart, genus: Genus, kasus: Kasus, numerus: Numerus) -> Option<String> {
+    let endung = endung(genus, kasus, numerus);
+    match art {
+        Artikelart::Bestimmt => Some(bestimmt(genus, kasus, numerus).to_string()),
+        Artikelart::Unbestimmt if numerus == Numerus::Plural => None,
+        Artikelart::Unbestimmt => Some(format!("ein{endung}")),
+        Artikelart::Negativ => Some(format!("kein{endung}")),
+        // "euer" loses its second "e" before an ending: "eure", "eurem".
+        Artikelart::Possessiv(Besitzer::Euer) if !endung.is_empty() => Some(format!("eur{endung}")),
+        Artikelart::Possessiv(besitzer) => Some(format!("{}{endung}", besitzer.name())),
+    }
+}
+
+/// Every form of an article kind, each once, e.g. to find articles in a sentence.
+pub fn formen(art: Artikelart) -> Vec<String> {
+    let mut formen = Vec::new();
+    for genus in Genus::ALLE {
+        for kasus in Kasus::ALLE {
+            for numerus in Numerus::ALLE {
+                if let Some(form) = artikel(art, genus, kasus, numerus) {
+                    if !formen.contains(&form) {
+                        formen.push(form);
+                    }
+                }
+            }
+        }
+    }
+    formen
+}
+
+/// The contraction of a preposition with a definite article, e.g. "zum" for "zu dem".
+pub fn verschmelze(praeposition: &str, artikel: &str) -> Option<&'static str> {
+    VERSCHMELZUNGEN
+        .iter()
+        .find(|(p, a, _)| *p == praeposition && *a == artikel)
+        .map(|(_, _, verschmolzen)| *verschmolzen)
+}
+
+fn bestimmt(genus: Genus, kasus: Kasus, numerus: Numerus) -> &'static str {
+    match (numerus, kasus, genus) {
+        (Numerus::Plural, Kasus::Nominativ | Kasus::Akkusativ, _) => "die",
+        (Numerus::Plural, Kasus::Genitiv, _) => "der",
+        (Numerus::Plural, Kasus::Dativ, _) => "den",
+        (_, Kasus::Nominativ, Genus::Maskulinum) => "der",
+        (_, Kasus::Akkusativ, Genus::Maskulinum) => "den",
+        (_, Kasus::Nominativ | Kasus::Akkusativ, Genus::Femininum) => "die",
+        (_, Kasus::Nominativ | Kasus::Akkusativ, Genus::Neutrum) => "das",
+        (_, Kasus::Genitiv | Kasus::Dativ, Genus::Femininum) => "der",
+        (_, Kasus::Genitiv, _) => "des",
+        (_, Kasus::Dativ, _) => "dem",
+    }
+}
+
+/// The ending of "ein", "kein" and the possessive articles.
+fn endung(genus: Genus, kasus: Kasus, numerus: Numerus) -> &'static str {
+    match (numerus, kasus, genus) {
+        (Numerus::Plural, Kasus::Nominativ | Kasus::Akkusativ, _) => "e",
+        (Numerus::Plural, Kasus::Genitiv, _) => "er",
+        (Numerus::Plural, Kasus::Dativ, _) => "en",
+        (_, Kasus::Nominativ | Kasus::Akkusativ, Genus::Femininum) => "e",
+        (_, Kasus::Genitiv | Kasus::Dativ, Genus::Femininum) => "er",
+        (_, Kasus::Nominativ, _) => "",
+        (_, Kasus::Akkusativ, Genus::Maskulinum) => "en",
+        (_, Kasus::Akkusativ, _) => "",
+        (_, Kasus::Genitiv, _) => "es",
+        (_, Kasus::Dativ, _) => "em",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn alle(art: Artikelart, genus: Genus, numerus: Numerus) -> Vec<Option<String>> {
+        Kasus::ALLE
+            .into_iter()
+            .map(|kasus| artikel(art, genus, kasus, numerus))
+            .collect()
+    }
+
+    fn some(formen: [&str; 4]) -> Vec<Option<String>> {
+        formen.into_iter().map(|f| Some(f.to_string())).collect()
+    }
+
+    #[test]
+    fn bestimmter_artikel() {
+        use Genus::*;
+        let art = Artikelart::Bestimmt;
+        assert_eq!(
+            alle(art, Maskulinum, Numerus::Singular),
+            some(["der", "des", "dem", "den"])
+        );
+        assert_eq!(
+            alle(art, Femininum, Numerus::Singular),
+            some(["die", "der", "der", "die"])
+        );
+        assert_eq!(
+            alle(art, Neutrum, Numerus::Singular),
+            some(["das", "des", "dem", "das"])
+        );
+        assert_eq!(
+            alle(art, Neutrum, Numerus::Plural),
+            some(["die", "der", "den", "die"])
+        );
+    }
+
+    #[test]
+    fn ein_woerter() {
+        use Genus::*;
+        let art = Artikelart::Unbestimmt;
+        assert_eq!(
+            alle(art, Maskulinum, Numerus::Singular),
+            some(["ein", "eines", "einem", "einen"])
+        );
+        assert_eq!(
+            alle(art, Femininum, Numerus::Singular),
+            some(["eine", "einer", "einer", "eine"])
+        );
+        assert_eq!(alle(art, Maskulinum, Numerus::Plural), vec![None; 4]);
+        let art = Artikelart::Negativ;
+        assert_eq!(
+            alle(art, Neutrum, Numerus::Singular),
+            some(["kein", "keines", "keinem", "kein"])
+        );
+        assert_eq!(
+            alle(art, Femininum, Numerus::Plural),
+            some(["keine", "keiner", "keinen", "keine"])
+        );
+        let art = Artikelart::Possessiv(Besitzer::Euer);
+        assert_eq!(
+            alle(art, Neutrum, Numerus::Singular),
+            some(["euer", "eures", "eurem", "euer"])
+        );
+        let art = Artikelart::Possessiv(Besitzer::Unser);
+        assert_eq!(
+            alle(art, Maskulinum, Numerus::Singular),
+            some(["unser", "unseres", "unserem", "unseren"])
+        );
+    }
+
+    #[test]
+    fn genus() {
+        assert_eq!(Genus::aus_kuerzel("f"), Some(Genus::Femininum));
+        assert_eq!(Genus::aus_kuerzel("x"), None);
+        assert_eq!(Genus::aus_artikel("Das"), Some(Genus::Neutrum));
+        assert_eq!(Genus::aus_artikel("den"), None);
+        assert_eq!(formen(Artikelart::Bestimmt).len(), 6);
+    }
+
+    #[test]
+    fn verschmelzungen() {
+        assert_eq!(verschmelze("zu", "der"), Some("zur"));
+        assert_eq!(verschmelze("bei", "dem"), Some("beim"));
+        assert_eq!(verschmelze("mit", "dem"), None);
+    }
+}
@@ -0,0 +1,102 @@
+//! Reads the `{{Aussprache}}` block: IPA, audio files and rhymes.
+//!
+//! ```text
+//! :{{IPA}} {{Lautschrift|ˈap͡fl̩}}, {{Pl.}} {{Lautschrift|ˈɛp͡fl̩}}
+//! :{{Hörbeispiele}} {{Audio|De-Apfel.ogg}}, {{Pl.}} {{Audio|De-Äpfel.ogg}}
+//! :{{Reime}} {{Reim|ap͡fl̩|Deutsch}}
+//! ```
+
+use crate::vorlage::{Knoten, Vorlage};
+
+/// The pronunciation of a word, in the order of the block. Everything after `{{Pl.}}`,
+/// `{{Pl.1}}`, … on a line belongs to a plural form.
+#[derive(PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Aussprache {
+    /// IPA from `{{Lautschrift|…}}`, e.g. `ˈap͡fl̩`.
+    pub ipa_singular: Vec<String>,
+    pub ipa_plural: Vec<String>,
+    /// Files from `{{Audio|…}}`, e.g. `De-Apfel.ogg`.
+    pub audio_singular: Vec<String>,
+    pub audio_plural: Vec<String>,
+    /// Rhyme keys from `{{Reim|…|Deutsch}}`, e.g. `ap͡fl̩`.
+    pub reime: Vec<String>,
+}
+
+impl Aussprache {
+    /// Reads the text of an `{{Aussprache}}` subsection.
+    pub fn new(text: &str) -> Self {
+        let mut aussprache = Self::default();
+        for zeile in text.lines().filter_map(|zeile| zeile.strip_prefix(':')) {
+            let mut plural = false;
+            for knoten in Knoten::alle(zeile) {
+                let Knoten::Vorlage(vorlage) = knoten else {
+                    continue;
+                };
+                let werte = match vorlage.name {
+                    name if name.starts_with("Pl.") => {
+                        plural = true;
+                        continue;
+                    }
+                    name if name.starts_with("Sg.") => {
+                        plural = false;
+                        continue;
+                    }
+                    "Lautschrift" if plural => &mut aussprache.ipa_plural,
+                    "Lautschrift" => &mut aussprache.ipa_singular,
+                    "Audio" if plural => &mut aussprache.audio_plural,
+                    "Audio" => &mut aussprache.audio_singular,
+                    "Reim" => &mut aussprache.reime,
+                    _ => continue,
+                };
+                werte.extend(erster_wert(&vorlage));
+            }
+        }
+        aussprache
+    }
+}
+
+/// The first positional parameter, unless it is empty or the `…` placeholder.
+fn erster_wert(vorlage: &Vorlage) -> Option<String> {
+    let wert = vorlage.unbenannt(1)?.text();
+    (!wert.is_empty() && wert != "…").then_some(wert)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn singular_und_plural() {
+        let foo = Aussprache::new(
+            ":{{IPA}} {{Lautschrift|ˈap͡fl̩}}, {{Pl.}} {{Lautschrift|ˈɛp͡fl̩}}
+:{{Hörbeispiele}} {{Audio|De-Apfel.ogg}}, {{Pl.}} {{Audio|De-Äpfel.ogg|spr=de}}
+:{{Reime}} {{Reim|ap͡fl̩|Deutsch}}
+
+",
+        );
+        assert_eq!(
+            foo,
+            Aussprache {
+                ipa_singular: vec!["ˈap͡fl̩".to_string()],
+                ipa_plural: vec!["ˈɛp͡fl̩".to_string()],
+                audio_singular: vec!["De-Apfel.ogg".to_string()],
+                audio_plural: vec!["De-Äpfel.ogg".to_string()],
+                reime: vec!["ap͡fl̩".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn platzhalter() {
+        let foo = Aussprache::new(
+            ":{{IPA}} {{Lautschrift|…}}, {{Pl.1}} {{Lautschrift|ˈvœʁtɐ}}, {{Pl.2}} {{Lautschrift|ˈvɔʁtə}}
+:{{Hörbeispiele}} {{Audio|}}
+:{{Reime}} {{Reim|ɔʁt|Deutsch}}, {{Reim|ʁt|Deutsch}}",
+        );
+        assert!(foo.ipa_singular.is_empty());
+        assert_eq!(foo.ipa_plural, ["ˈvœʁtɐ", "ˈvɔʁtə"]);
+        assert!(foo.audio_singular.is_empty());
+        assert_eq!(foo.reime, ["ɔʁt", "ʁt"]);
+    }
+}
@@ -0,0 +1,70 @@
+//! Reads the numbered meanings of a `{{Bedeutungen}}` block as plain text.
+
+use crate::vorlage::fliesstext;
+
+/// One meaning, e.g. `:[1] {{K|Anatomie}} [[Knochen]], der die [[Zähne]] trägt` gives the
+/// number `1` and the text `Knochen, der die Zähne trägt`. Sub-meanings like `::[2a] …` keep
+/// their number `2a`.
+#[derive(PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bedeutung {
+    pub nummer: String,
+    pub text: String,
+}
+
+impl Bedeutung {
+    /// Reads the text of a `{{Bedeutungen}}` subsection. Lines without a number are skipped.
+    pub fn alle(text: &str) -> Vec<Self> {
+        text.lines()
+            .take_while(|zeile| zeile.starts_with(':'))
+            .filter_map(|zeile| {
+                let zeile = zeile.trim_start_matches(':').trim_start();
+                let (nummer, rest) = zeile.strip_prefix('[')?.split_once(']')?;
+                let text = fliesstext(rest);
+                (!nummer.trim().is_empty() && !text.is_empty()).then(|| Self {
+                    nummer: nummer.trim().to_string(),
+                    text,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bedeutung(nummer: &str, text: &str) -> Bedeutung {
+        Bedeutung {
+            nummer: nummer.to_string(),
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn nummeriert() {
+        let foo = Bedeutung::alle(
+            ":[1] {{K|Botanik}} [[Nadelbaum]] mit [[lang]]en [[Nadel]]n
+:[2] {{K|Anatomie}} [[Knochen]], der die [[Zähne]] trägt
+::[2a] ''[[Oberkiefer]]''
+:ohne Nummer
+
+{{Beispiele}}
+:[1] Die ''Kiefer'' wächst.",
+        );
+        assert_eq!(
+            foo,
+            [
+                bedeutung("1", "Nadelbaum mit langen Nadeln"),
+                bedeutung("2", "Knochen, der die Zähne trägt"),
+                bedeutung("2a", "Oberkiefer"),
+            ]
+        );
+    }
+
+    #[test]
+    fn leer() {
+        assert_eq!(Bedeutung::alle(""), []);
+        assert_eq!(Bedeutung::alle(":[1]\n:[2] {{Ü|en|x}}"), []);
+    }
+}
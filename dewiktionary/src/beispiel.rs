@@ -0,0 +1,145 @@
+//! Reads the example sentences of a `{{Beispiele}}` block as plain text.
+
+use crate::artikel::{self, Artikelart};
+use crate::vorlage::fliesstext;
+
+/// Stands for the masked article.
+pub const LUECKE: &str = "___";
+
+/// One example sentence, e.g. `:[1, 2] Er gab mir eine ''Kopfnuss.''<ref>…</ref>` gives the
+/// meanings `1` and `2` and the text `Er gab mir eine Kopfnuss.`.
+#[derive(PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Beispiel {
+    pub bedeutungen: Vec<String>,
+    pub text: String,
+}
+
+/// An example sentence with the article of the noun replaced by [`LUECKE`].
+#[derive(PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Maskierung {
+    /// The masked article as written in the sentence, e.g. `Der`.
+    pub artikel: String,
+    pub text: String,
+}
+
+impl Beispiel {
+    /// Reads the text of a `{{Beispiele}}` subsection. References and markup are removed, and
+    /// lines without a meaning number or without text are skipped.
+    pub fn alle(text: &str) -> Vec<Self> {
+        text.lines()
+            .take_while(|zeile| zeile.starts_with(':'))
+            .filter_map(|zeile| {
+                let zeile = zeile.trim_start_matches(':').trim_start();
+                let (nummern, rest) = zeile.strip_prefix('[')?.split_once(']')?;
+                let text = fliesstext(rest);
+                (!text.is_empty()).then(|| Self {
+                    bedeutungen: nummern
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|nummer| !nummer.is_empty())
+                        .map(str::to_string)
+                        .collect(),
+                    text,
+                })
+            })
+            .collect()
+    }
+
+    /// Masks the article of the first of the given noun forms that has one. Adjectives between
+    /// the article and the noun are skipped, so "der größte See" masks "der".
+    pub fn maskiere(&self, formen: &[&str]) -> Option<Maskierung> {
+        // The articles that can be masked in front of a noun.
+        let alle_artikel = [
+            artikel::formen(Artikelart::Bestimmt),
+            artikel::formen(Artikelart::Unbestimmt),
+        ]
+        .concat();
+        let mut woerter: Vec<&str> = self.text.split(' ').collect();
+        for i in 1..woerter.len() {
+            let wort = woerter[i].trim_matches(|c: char| !c.is_alphanumeric() && c != '-');
+            if !formen.contains(&wort) {
+                continue;
+            }
+            for j in (i.saturating_sub(3)..i).rev() {
+                let vorher = woerter[j].trim_start_matches(|c: char| !c.is_alphabetic());
+                if alle_artikel.contains(&vorher.to_lowercase()) {
+                    let artikel = vorher.to_string();
+                    let anfang = &woerter[j][..woerter[j].len() - vorher.len()];
+                    let luecke = format!("{anfang}{LUECKE}");
+                    woerter[j] = &luecke;
+                    return Some(Maskierung {
+                        artikel,
+                        text: woerter.join(" "),
+                    });
+                }
+                if !vorher.starts_with(char::is_lowercase) {
+                    break;
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn beispiel(text: &str) -> Beispiel {
+        Beispiel {
+            bedeutungen: vec!["1".to_string()],
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn ohne_referenzen() {
+        let foo = Beispiel::alle(
+            ":[1] Er gab mir eine ''Kopfnuss.''
+:[1, 2] „Hat man das nicht getan, hat man eine ''Kopfnuss'' bekommen.“<ref>{{Per-Bayerischer Rundfunk | Online=https://www.br.de/ | Titel=Sternekoch }}</ref>
+:[2]
+:kein Beispiel
+
+{{Übersetzungen}}",
+        );
+        assert_eq!(
+            foo,
+            [
+                beispiel("Er gab mir eine Kopfnuss."),
+                Beispiel {
+                    bedeutungen: vec!["1".to_string(), "2".to_string()],
+                    text: "„Hat man das nicht getan, hat man eine Kopfnuss bekommen.“".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn artikel_maskieren() {
+        let maskierung = beispiel("Der Bodensee ist der größte See Deutschlands.")
+            .maskiere(&["See", "Sees", "Seen"])
+            .unwrap();
+        assert_eq!(maskierung.artikel, "der");
+        assert_eq!(
+            maskierung.text,
+            "Der Bodensee ist ___ größte See Deutschlands."
+        );
+
+        let maskierung = beispiel("„Die Kopfnuss tat weh.“")
+            .maskiere(&["Kopfnuss"])
+            .unwrap();
+        assert_eq!(maskierung.artikel, "Die");
+        assert_eq!(maskierung.text, "„___ Kopfnuss tat weh.“");
+
+        assert_eq!(
+            beispiel("Im Sommer gehen wir im See schwimmen.").maskiere(&["See"]),
+            None
+        );
+        assert_eq!(
+            beispiel("Der Mann sieht See und Berge.").maskiere(&["See"]),
+            None
+        );
+    }
+}
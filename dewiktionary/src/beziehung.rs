@@ -0,0 +1,124 @@
+//! Reads the lexical relations of the `{{Synonyme}}`, `{{Gegenwörter}}`, `{{Oberbegriffe}}`,
+//! `{{Unterbegriffe}}` and `{{Sinnverwandte Wörter}}` blocks, e.g. `:[1] [[Bergsee]], [[Stausee]]`.
+
+use crate::seite::Abschnittsart;
+use crate::vorlage::Knoten;
+
+/// The kind of a relation, named after the block it is read from.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Beziehungsart {
+    Synonym,
+    Gegenwort,
+    Oberbegriff,
+    Unterbegriff,
+    Sinnverwandt,
+}
+
+impl Beziehungsart {
+    pub const ALLE: [Self; 5] = [
+        Self::Synonym,
+        Self::Gegenwort,
+        Self::Oberbegriff,
+        Self::Unterbegriff,
+        Self::Sinnverwandt,
+    ];
+
+    /// The subsection the relations of this kind are listed in.
+    pub fn abschnittsart(self) -> Abschnittsart<'static> {
+        match self {
+            Self::Synonym => Abschnittsart::Synonyme,
+            Self::Gegenwort => Abschnittsart::Gegenwoerter,
+            Self::Oberbegriff => Abschnittsart::Oberbegriffe,
+            Self::Unterbegriff => Abschnittsart::Unterbegriffe,
+            Self::Sinnverwandt => Abschnittsart::SinnverwandteWoerter,
+        }
+    }
+
+    /// A lower-case name, e.g. to store the kind in a database.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Synonym => "synonym",
+            Self::Gegenwort => "gegenwort",
+            Self::Oberbegriff => "oberbegriff",
+            Self::Unterbegriff => "unterbegriff",
+            Self::Sinnverwandt => "sinnverwandt",
+        }
+    }
+}
+
+/// A relation to another lemma, e.g. the hyponym `Bergsee` of meaning `1` of "See".
+#[derive(PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Beziehung {
+    pub art: Beziehungsart,
+    /// The meaning numbers the relation belongs to, e.g. `1` and `2` for `[1, 2]`.
+    pub bedeutungen: Vec<String>,
+    pub ziel: String,
+}
+
+impl Beziehung {
+    /// Reads the text of the subsection of the given kind. Every link of a line is a relation,
+    /// except links into other namespaces such as `[[Wikipedia:See]]`.
+    pub fn alle(art: Beziehungsart, text: &str) -> Vec<Self> {
+        let mut beziehungen = Vec::new();
+        for zeile in text.lines().take_while(|zeile| zeile.starts_with(':')) {
+            let zeile = zeile.trim_start_matches(':').trim_start();
+            let Some((nummern, rest)) = zeile.strip_prefix('[').and_then(|z| z.split_once(']'))
+            else {
+                continue;
+            };
+            let bedeutungen: Vec<String> = nummern
+                .split(',')
+                .map(str::trim)
+                .filter(|nummer| !nummer.is_empty())
+                .map(str::to_string)
+                .collect();
+            for knoten in Knoten::alle(rest) {
+                if let Knoten::Verweis(verweis) = knoten {
+                    let ziel = verweis.ziel.split('#').next().unwrap_or_default().trim();
+                    if !ziel.is_empty() && !ziel.contains(':') {
+                        beziehungen.push(Self {
+                            art,
+                            bedeutungen: bedeutungen.clone(),
+                            ziel: ziel.to_string(),
+                        });
+                    }
+                }
+            }
+        }
+        beziehungen
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verweise() {
+        let foo = Beziehung::alle(
+            Beziehungsart::Unterbegriff,
+            ":[1] [[Bergsee]], [[Stausee|Stauseen]]; {{ugs.}} [[Tümpel#Substantiv]]
+:[1, 2] [[Wikipedia:See]] [[Meer]]
+:[*] ohne Verweis
+
+{{Beispiele}}
+:[1] Im Sommer gehen wir im ''[[See]]'' schwimmen.",
+        );
+        let ziele: Vec<(&str, &[String])> = foo
+            .iter()
+            .map(|b| (b.ziel.as_str(), b.bedeutungen.as_slice()))
+            .collect();
+        assert_eq!(
+            ziele,
+            [
+                ("Bergsee", &["1".to_string()][..]),
+                ("Stausee", &["1".to_string()][..]),
+                ("Tümpel", &["1".to_string()][..]),
+                ("Meer", &["1".to_string(), "2".to_string()][..]),
+            ]
+        );
+        assert!(foo.iter().all(|b| b.art == Beziehungsart::Unterbegriff));
+    }
+}
@@ -0,0 +1,94 @@
+//! Why a table could not be read.
+//!
+//! Offsets are byte offsets into the text that was parsed. [`Substantivabschnitt::lies`]
+//! shifts them so they point into the whole page.
+//!
+//! [`Substantivabschnitt::lies`]: crate::parser::Substantivabschnitt::lies
+
+use std::fmt;
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Parsefehler {
+    /// The text has no noun table, e.g. because the section uses a template we do not know.
+    KeineTabelle,
+    /// A table starts but cannot be parsed, usually because a `}}` is missing. `offset` is
+    /// where the parser stopped and `grund` the nom error it stopped with.
+    Unlesbar { offset: usize, grund: String },
+    /// A parameter has a value that does not fit its field, e.g. `|Genus=x`.
+    UngueltigerWert {
+        offset: usize,
+        feld: String,
+        wert: String,
+    },
+    /// A field every noun needs, like `Genus` or `Nominativ Singular`, is missing.
+    FeldFehlt { offset: usize, feld: &'static str },
+}
+
+impl Parsefehler {
+    /// Where the error was found.
+    pub fn offset(&self) -> Option<usize> {
+        match self {
+            Self::KeineTabelle => None,
+            Self::Unlesbar { offset, .. }
+            | Self::UngueltigerWert { offset, .. }
+            | Self::FeldFehlt { offset, .. } => Some(*offset),
+        }
+    }
+
+    /// The template parameter that failed.
+    pub fn feld(&self) -> Option<&str> {
+        match self {
+            Self::KeineTabelle | Self::Unlesbar { .. } => None,
+            Self::UngueltigerWert { feld, .. } => Some(feld),
+            Self::FeldFehlt { feld, .. } => Some(feld),
+        }
+    }
+
+    /// The same error for a text that starts `um` bytes later.
+    pub(crate) fn verschoben(mut self, um: usize) -> Self {
+        match &mut self {
+            Self::KeineTabelle => {}
+            Self::Unlesbar { offset, .. }
+            | Self::UngueltigerWert { offset, .. }
+            | Self::FeldFehlt { offset, .. } => *offset += um,
+        }
+        self
+    }
+}
+
+impl fmt::Display for Parsefehler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::KeineTabelle => write!(f, "keine Substantivtabelle"),
+            Self::Unlesbar { offset, grund } => {
+                write!(f, "Tabelle bei Byte {offset} nicht lesbar: {grund}")
+            }
+            Self::UngueltigerWert { offset, feld, wert } => {
+                write!(f, "ungültiger Wert „{wert}“ für {feld} bei Byte {offset}")
+            }
+            Self::FeldFehlt { offset, feld } => {
+                write!(f, "{feld} fehlt in der Tabelle bei Byte {offset}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Parsefehler {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verschoben() {
+        let fehler = Parsefehler::FeldFehlt {
+            offset: 3,
+            feld: "Genus",
+        };
+        assert_eq!(fehler.clone().verschoben(10).offset(), Some(13));
+        assert_eq!(fehler.feld(), Some("Genus"));
+        assert_eq!(fehler.to_string(), "Genus fehlt in der Tabelle bei Byte 3");
+        assert_eq!(Parsefehler::KeineTabelle.verschoben(10).offset(), None);
+    }
+}
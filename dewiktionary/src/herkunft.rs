@@ -0,0 +1,140 @@
+//! Reads how a compound is formed from a `{{Herkunft}}` block, e.g.
+//! `:[[Determinativkompositum]] aus den [[Substantiv]]en ''[[Kopf]]'' und ''[[Nuss]]''`.
+
+use crate::vorlage::{klartext, Knoten};
+
+/// Words that introduce the formation of a compound, compared in lower case. The kinds of
+/// compounds such as "Determinativkompositum" or "Kopulativkompositum" end in "kompositum".
+const KOMPOSITUM: [&str; 3] = ["kompositum", "komposition", "zusammensetzung"];
+
+/// The parts of a compound in the order of the word. The last part is the head word.
+#[derive(PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Zusammensetzung {
+    pub teile: Vec<Bestandteil>,
+}
+
+/// A part of a compound and the linking element that follows it, e.g. `Arbeit` and `s` for
+/// "Arbeitszimmer".
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bestandteil {
+    pub wort: String,
+    /// The linking element without hyphens, e.g. `s` for `''-s-''`.
+    pub fuge: Option<String>,
+}
+
+impl Zusammensetzung {
+    /// Reads the text of a `{{Herkunft}}` subsection. The parts are the words in italics after
+    /// "…kompositum aus", and a part after "Fugenelement" is the linking element. Herkunft
+    /// texts without a compound with at least two parts give `None`.
+    pub fn new(text: &str) -> Option<Self> {
+        text.lines()
+            .filter_map(|zeile| zeile.strip_prefix(':'))
+            .find_map(Self::aus_zeile)
+    }
+
+    fn aus_zeile(zeile: &str) -> Option<Self> {
+        let zeile = klartext(&Knoten::alle(zeile)).replace("'''", "");
+        // Unlike `to_lowercase`, this keeps the byte offsets of `zeile`.
+        let klein = zeile.to_ascii_lowercase();
+        let anfang = KOMPOSITUM.iter().filter_map(|w| klein.find(w)).min()?;
+        let (_, rest) = klein[anfang..].split_once(" aus ")?;
+        let rest = &zeile[zeile.len() - rest.len()..];
+        let rest = rest.split(';').next().unwrap_or_default();
+
+        let mut teile: Vec<Bestandteil> = Vec::new();
+        // The linking elements with the number of parts before them.
+        let mut fugen = Vec::new();
+        let mut davor = "";
+        for (i, stueck) in rest.split("''").enumerate() {
+            if i % 2 == 0 {
+                davor = stueck;
+                continue;
+            }
+            let wort = stueck.trim().trim_end_matches([',', '.']).trim();
+            if wort.is_empty() {
+                continue;
+            }
+            if davor.trim_end().ends_with("Fugenelement") || wort.starts_with('-') {
+                fugen.push((teile.len(), wort.trim_matches('-').to_string()));
+            } else {
+                teile.push(Bestandteil {
+                    wort: wort.to_string(),
+                    fuge: None,
+                });
+            }
+        }
+        if teile.len() < 2 {
+            return None;
+        }
+        // "aus ''Sonne'' und ''Schein'' sowie dem Fugenelement ''-n''" joins the last two parts.
+        let letzte = teile.len() - 1;
+        for (vorher, fuge) in fugen {
+            teile[vorher.clamp(1, letzte) - 1].fuge = Some(fuge).filter(|f| !f.is_empty());
+        }
+        Some(Self { teile })
+    }
+
+    /// The last part, e.g. `Nuss` for "Kopfnuss".
+    pub fn kopf(&self) -> &str {
+        &self.teile[self.teile.len() - 1].wort
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn teil(wort: &str, fuge: Option<&str>) -> Bestandteil {
+        Bestandteil {
+            wort: wort.to_string(),
+            fuge: fuge.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn ohne_fuge() {
+        let foo = Zusammensetzung::new(
+            ":[[Determinativkompositum]] ([[Zusammensetzung]]) aus den [[Substantiv]]en ''[[Kopf]]'' und ''[[Nuss]]''\n",
+        )
+        .unwrap();
+        assert_eq!(foo.teile, [teil("Kopf", None), teil("Nuss", None)]);
+        assert_eq!(foo.kopf(), "Nuss");
+    }
+
+    #[test]
+    fn mit_fuge() {
+        let foo = Zusammensetzung::new(
+            ":[[Determinativkompositum]] aus ''[[Arbeit]],'' dem [[Fugenelement]] ''[[-s-]]'' und ''[[Zimmer]]''",
+        )
+        .unwrap();
+        assert_eq!(foo.teile, [teil("Arbeit", Some("s")), teil("Zimmer", None)]);
+
+        let foo = Zusammensetzung::new(
+            ":[[Determinativkompositum]] aus den Substantiven ''[[Sonne]]'' und ''[[Schein]]'' sowie dem [[Fugenelement]] ''[[-n]]''; seit dem 15. Jahrhundert belegt",
+        )
+        .unwrap();
+        assert_eq!(foo.teile, [teil("Sonne", Some("n")), teil("Schein", None)]);
+    }
+
+    #[test]
+    fn keine_zusammensetzung() {
+        assert_eq!(
+            Zusammensetzung::new(
+                ":[[mittelhochdeutsch]] ''{{lang|gmh|sē}},'' [[althochdeutsch]] ''{{lang|goh|sē(o)}}''"
+            ),
+            None
+        );
+        assert_eq!(
+            Zusammensetzung::new(
+                ":[[Ableitung]] zum Verb ''[[lesen]]'' mit dem Derivatem ''[[-ung]]''"
+            ),
+            None
+        );
+        assert_eq!(
+            Zusammensetzung::new(":[[Kompositum]] aus ''[[Kopf]]''"),
+            None
+        );
+    }
+}
@@ -1 +1,16 @@
+pub mod artikel;
+pub mod aussprache;
+pub mod bedeutung;
+pub mod beispiel;
+pub mod beziehung;
+pub mod fehler;
+pub mod herkunft;
 pub mod parser;
+pub mod plural;
+pub mod seite;
+pub mod silbentrennung;
+pub mod uebersetzung;
+pub mod vorhersage;
+pub mod vorlage;
+pub mod worttrennung;
+pub mod zerlegung;
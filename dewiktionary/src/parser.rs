@@ -1,279 +1,658 @@
-use nom::bytes::complete::tag;
-use nom::character::complete::{alpha1, anychar, multispace0};
-use nom::combinator::{map, recognize};
+use std::collections::BTreeMap;
 
-use nom::multi::many_till;
-use nom::sequence::{delimited, preceded, tuple};
-use nom::IResult;
+use nom::Offset;
 
-#[derive(PartialEq, Eq, Debug)]
-pub struct Genus {
-    pub genus: String,
+use crate::aussprache::Aussprache;
+use crate::bedeutung::Bedeutung;
+use crate::beispiel::Beispiel;
+use crate::beziehung::{Beziehung, Beziehungsart};
+use crate::fehler::Parsefehler;
+use crate::herkunft::Zusammensetzung;
+use crate::seite::{Abschnittsart, Seite};
+use crate::uebersetzung::Uebersetzung;
+use crate::vorlage::Vorlage;
+use crate::worttrennung::Worttrennung;
+
+/// The grammatical case of a noun form.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Kasus {
+    Nominativ,
+    Genitiv,
+    Dativ,
+    Akkusativ,
 }
-impl Genus {
-    fn parse(input: &str) -> IResult<&str, Self> {
-        let a = preceded(tag("="), alpha1);
-        let b = preceded(tag("Genus"), a);
-        let c = preceded(tag("|"), b);
-        let d = preceded(multispace0, c);
-
-        let mut my_parser = map(d, |rest: &str| Self {
-            genus: rest.to_string(),
-        });
 
-        my_parser(input)
+impl Kasus {
+    /// The cases in the order of the noun tables.
+    pub const ALLE: [Self; 4] = [Self::Nominativ, Self::Genitiv, Self::Dativ, Self::Akkusativ];
+
+    /// A lower-case name, e.g. to store the case in a database.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Nominativ => "nominativ",
+            Self::Genitiv => "genitiv",
+            Self::Dativ => "dativ",
+            Self::Akkusativ => "akkusativ",
+        }
     }
 }
 
-#[derive(PartialEq, Eq, Debug)]
-pub struct NominativSingular {
-    pub text: String,
+/// The grammatical number of a noun form.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Numerus {
+    Singular,
+    Plural,
 }
 
-impl NominativSingular {
-    fn parse(input: &str) -> IResult<&str, Self> {
-        let a = preceded(tag("="), alpha1);
-        let a1 = preceded(tag("Singular"), a);
-        let a2 = preceded(multispace0, a1);
+impl Numerus {
+    pub const ALLE: [Self; 2] = [Self::Singular, Self::Plural];
 
-        let b = preceded(tag("Nominativ"), a2);
-        let c = preceded(tag("|"), b);
-        let d = preceded(multispace0, c);
-
-        let mut my_parser = map(d, |rest: &str| Self {
-            text: rest.to_string(),
-        });
-
-        my_parser(input)
+    /// A lower-case name, e.g. to store the number in a database.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Singular => "singular",
+            Self::Plural => "plural",
+        }
     }
 }
 
-#[derive(PartialEq, Eq, Debug)]
-pub struct NominativPlural {
+/// One case form of a noun, e.g. the genitive plural "Kopfnüsse".
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Form {
+    pub kasus: Kasus,
+    pub numerus: Numerus,
     pub text: String,
 }
-impl NominativPlural {
-    fn parse(input: &str) -> IResult<&str, Self> {
-        let a = preceded(tag("="), alpha1);
-        let a1 = preceded(tag("Plural"), a);
-        let a2 = preceded(multispace0, a1);
-
-        let b = preceded(tag("Nominativ"), a2);
-        let c = preceded(tag("|"), b);
-        let d = preceded(multispace0, c);
-
-        let mut my_parser = map(d, |rest: &str| Self {
-            text: rest.to_string(),
-        });
 
-        my_parser(input)
+impl Form {
+    pub fn new(kasus: Kasus, numerus: Numerus, text: &str) -> Self {
+        Self {
+            kasus,
+            numerus,
+            text: text.to_string(),
+        }
     }
 }
 
-#[derive(PartialEq, Eq, Debug)]
-pub struct GenitivSingular {
-    pub text: String,
+/// The case and number a parameter like `Genitiv Plural` stands for.
+fn fall(schluessel: &str) -> Option<(Kasus, Numerus)> {
+    let (kasus, numerus) = schluessel.split_once(' ')?;
+    let kasus = Kasus::ALLE
+        .into_iter()
+        .find(|k| k.name().eq_ignore_ascii_case(kasus))?;
+    let numerus = Numerus::ALLE
+        .into_iter()
+        .find(|n| n.name().eq_ignore_ascii_case(numerus))?;
+    Some((kasus, numerus))
 }
-impl GenitivSingular {
-    fn parse(input: &str) -> IResult<&str, Self> {
-        let a = preceded(tag("="), alpha1);
-        let a1 = preceded(tag("Singular"), a);
-        let a2 = preceded(multispace0, a1);
-
-        let b = preceded(tag("Genitiv"), a2);
-        let c = preceded(tag("|"), b);
-        let d = preceded(multispace0, c);
-
-        let mut my_parser = map(d, |rest: &str| Self {
-            text: rest.to_string(),
-        });
 
-        my_parser(input)
+/// The kind of noun a table template stands for.
+#[derive(PartialEq, Eq, Debug, Default, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Substantivart {
+    /// `{{Deutsch Substantiv Übersicht}}`.
+    #[default]
+    Substantiv,
+    /// `{{Deutsch adjektivisch Übersicht}}` for nouns declined like adjectives, e.g. "der
+    /// Beamte" and "ein Beamter".
+    Adjektivisch,
+    /// `{{Deutsch Eigenname Übersicht}}`, `{{Deutsch Vorname Übersicht m}}` or
+    /// `{{Deutsch Nachname Übersicht}}`.
+    Eigenname,
+    /// `{{Deutsch Toponym Übersicht}}`.
+    Toponym,
+}
+
+impl Substantivart {
+    pub const ALLE: [Self; 4] = [
+        Self::Substantiv,
+        Self::Adjektivisch,
+        Self::Eigenname,
+        Self::Toponym,
+    ];
+
+    /// A lower-case name, e.g. to store the kind in a database.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Substantiv => "substantiv",
+            Self::Adjektivisch => "adjektivisch",
+            Self::Eigenname => "eigenname",
+            Self::Toponym => "toponym",
+        }
     }
 }
 
-#[derive(PartialEq, Eq, Debug)]
-pub struct GenitivPlural {
-    pub text: String,
+/// A noun that only has singular forms, like "Durst", or only plural forms, like "Leute".
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Tantum {
+    Singular,
+    Plural,
 }
-impl GenitivPlural {
-    fn parse(input: &str) -> IResult<&str, Self> {
-        let a = preceded(tag("="), alpha1);
-        let a1 = preceded(tag("Plural"), a);
-        let a2 = preceded(multispace0, a1);
-
-        let b = preceded(tag("Genitiv"), a2);
-        let c = preceded(tag("|"), b);
-        let d = preceded(multispace0, c);
-
-        let mut my_parser = map(d, |rest: &str| Self {
-            text: rest.to_string(),
-        });
 
-        my_parser(input)
+impl Tantum {
+    pub const ALLE: [Self; 2] = [Self::Singular, Self::Plural];
+
+    /// A lower-case name, e.g. to store the flag in a database.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Singular => "singular",
+            Self::Plural => "plural",
+        }
     }
 }
 
-#[derive(PartialEq, Eq, Debug)]
-pub struct DativSingular {
-    pub text: String,
+/// The noun table templates, their kind and the gender a template implies.
+const SUBSTANTIVVORLAGEN: [(&str, Substantivart, Option<&str>); 8] = [
+    (
+        "Deutsch Substantiv Übersicht",
+        Substantivart::Substantiv,
+        None,
+    ),
+    (
+        "Deutsch Substantiv Übersicht -sch",
+        Substantivart::Substantiv,
+        Some("n"),
+    ),
+    (
+        "Deutsch adjektivisch Übersicht",
+        Substantivart::Adjektivisch,
+        None,
+    ),
+    (
+        "Deutsch Eigenname Übersicht",
+        Substantivart::Eigenname,
+        None,
+    ),
+    ("Deutsch Nachname Übersicht", Substantivart::Eigenname, None),
+    (
+        "Deutsch Vorname Übersicht m",
+        Substantivart::Eigenname,
+        Some("m"),
+    ),
+    (
+        "Deutsch Vorname Übersicht f",
+        Substantivart::Eigenname,
+        Some("f"),
+    ),
+    ("Deutsch Toponym Übersicht", Substantivart::Toponym, None),
+];
+
+/// The parts of speech of a heading whose section has a noun table. Proper names are often
+/// headed `{{Wortart|Vorname|Deutsch}}` or `{{Wortart|Toponym|Deutsch}}` alone.
+const SUBSTANTIVISCH: [&str; 5] = ["Substantiv", "Eigenname", "Nachname", "Vorname", "Toponym"];
+
+/// Stands for a form that does not exist, e.g. the plural of "Durst".
+fn fehlt(text: &str) -> bool {
+    matches!(text, "" | "—" | "–" | "-")
 }
-impl DativSingular {
-    fn parse(input: &str) -> IResult<&str, Self> {
-        let a = preceded(tag("="), alpha1);
-        let a1 = preceded(tag("Singular"), a);
-        let a2 = preceded(multispace0, a1);
-
-        let b = preceded(tag("Dativ"), a2);
-        let c = preceded(tag("|"), b);
-        let d = preceded(multispace0, c);
-
-        let mut my_parser = map(d, |rest: &str| Self {
-            text: rest.to_string(),
+
+/// The parameters of a `{{Deutsch Substantiv Übersicht}}` template or of one of its variants,
+/// see [`Substantivart`].
+///
+/// The parameters may come in any order. The genders and the case forms are kept in the order
+/// of the template, so `|Genus 1=m` and `|Genus 2=n` give two genders and `|Nominativ Plural 1`
+/// to `|Nominativ Plural 4` give up to four plurals, see [`DeutschSubstantivUebersicht::form`].
+/// Alternatives marked with a star count like numbered ones, so `|Genitiv Singular=Baumes` and
+/// `|Genitiv Singular*=Baums` give two genitives. A case that is missing, left empty or given
+/// as `—` has no forms, and every unknown, non-empty parameter is kept in `extra`.
+#[derive(PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeutschSubstantivUebersicht {
+    pub art: Substantivart,
+    /// Set if all forms of one number are given as `—`.
+    pub tantum: Option<Tantum>,
+    /// `m`, `f` or `n`.
+    pub genus: Vec<String>,
+    pub formen: Vec<Form>,
+    pub extra: BTreeMap<String, String>,
+}
+
+impl DeutschSubstantivUebersicht {
+    /// Reads the first noun table of the given text, e.g. `{{Deutsch Substantiv Übersicht}}`
+    /// or `{{Deutsch adjektivisch Übersicht}}`.
+    ///
+    /// A table that is not closed gives [`Parsefehler::Unlesbar`] and a gender other than `m`,
+    /// `f`, `n` or `0` gives [`Parsefehler::UngueltigerWert`].
+    pub fn new(input: &str) -> Result<Self, Parsefehler> {
+        let vorlagen = Vorlage::alle(input);
+        let gefunden = vorlagen.iter().find_map(|vorlage| {
+            let (_, art, genus) = SUBSTANTIVVORLAGEN
+                .iter()
+                .find(|(name, _, _)| *name == vorlage.name)?;
+            Some((vorlage, *art, *genus))
         });
+        match gefunden {
+            Some((vorlage, art, genus)) => Self::from_vorlage(input, vorlage, art, genus),
+            None => Err(Self::unlesbar(input).unwrap_or(Parsefehler::KeineTabelle)),
+        }
+    }
 
-        my_parser(input)
+    /// The error of a noun table that [`Vorlage::alle`] kept as plain text.
+    fn unlesbar(input: &str) -> Option<Parsefehler> {
+        input.match_indices("{{").find_map(|(beginn, _)| {
+            let name = input[beginn + 2..].trim_start();
+            if !SUBSTANTIVVORLAGEN
+                .iter()
+                .any(|(vorlage, _, _)| name.starts_with(vorlage))
+            {
+                return None;
+            }
+            // A table that parses on its own was nested in other markup, e.g. a comment.
+            match Vorlage::parse(&input[beginn..]) {
+                Err(nom::Err::Error(fehler) | nom::Err::Failure(fehler)) => {
+                    Some(Parsefehler::Unlesbar {
+                        offset: input.offset(fehler.input),
+                        grund: fehler.code.description().to_string(),
+                    })
+                }
+                Ok(_) | Err(nom::Err::Incomplete(_)) => None,
+            }
+        })
     }
-}
 
-#[derive(PartialEq, Eq, Debug)]
-pub struct DativPlural {
-    pub text: String,
+    fn from_vorlage(
+        input: &str,
+        vorlage: &Vorlage,
+        art: Substantivart,
+        genus: Option<&str>,
+    ) -> Result<Self, Parsefehler> {
+        let mut uebersicht = Self {
+            art,
+            ..Self::default()
+        };
+        // Whether a singular or a plural case was given as `—`.
+        let mut ohne_singular = false;
+        let mut ohne_plural = false;
+        for parameter in &vorlage.parameter {
+            let Some(name) = parameter.name else {
+                continue;
+            };
+            let text = parameter.text();
+            let schluessel = schluessel(name);
+            if fehlt(&text) {
+                ohne_singular |= !text.is_empty() && schluessel.ends_with(" Singular");
+                ohne_plural |= !text.is_empty() && schluessel.ends_with(" Plural");
+                continue;
+            }
+            match schluessel.as_str() {
+                // Pluralia tantum may give the gender `0`.
+                "Genus" if text == "0" => {}
+                "Genus" if !matches!(text.as_str(), "m" | "f" | "n") => {
+                    return Err(Parsefehler::UngueltigerWert {
+                        offset: input.offset(name),
+                        feld: name.to_string(),
+                        wert: text,
+                    });
+                }
+                "Genus" => uebersicht.genus.push(text),
+                _ => match fall(&schluessel) {
+                    Some((kasus, numerus)) => uebersicht.formen.push(Form {
+                        kasus,
+                        numerus,
+                        text,
+                    }),
+                    None => {
+                        uebersicht.extra.entry(name.to_string()).or_insert(text);
+                    }
+                },
+            }
+        }
+        if let (true, Some(genus)) = (uebersicht.genus.is_empty(), genus) {
+            uebersicht.genus.push(genus.to_string());
+        }
+        if art == Substantivart::Adjektivisch {
+            uebersicht.adjektivische_formen();
+        }
+        let singular = !uebersicht
+            .form(Kasus::Nominativ, Numerus::Singular)
+            .is_empty();
+        let plural = !uebersicht
+            .form(Kasus::Nominativ, Numerus::Plural)
+            .is_empty();
+        uebersicht.tantum = match (singular, plural) {
+            (true, false) if ohne_plural => Some(Tantum::Singular),
+            (false, true) if ohne_singular => Some(Tantum::Plural),
+            _ => None,
+        };
+        Ok(uebersicht)
+    }
+
+    /// Every form of the given case and number in the order of the table.
+    pub fn form(&self, kasus: Kasus, numerus: Numerus) -> Vec<&str> {
+        self.formen
+            .iter()
+            .filter(|f| f.kasus == kasus && f.numerus == numerus)
+            .map(|f| f.text.as_str())
+            .collect()
+    }
+
+    /// Every case and number with its forms, from the nominative singular to the accusative
+    /// plural. Cases without a form are not left out.
+    pub fn alle_formen(&self) -> impl Iterator<Item = (Kasus, Numerus, Vec<&str>)> + '_ {
+        Kasus::ALLE.into_iter().flat_map(move |kasus| {
+            Numerus::ALLE
+                .into_iter()
+                .map(move |numerus| (kasus, numerus, self.form(kasus, numerus)))
+        })
+    }
+
+    /// `{{Deutsch adjektivisch Übersicht}}` may only give the forms after the definite article,
+    /// e.g. `|Singular=Beamte` and `|Plural=Beamten`, from which the missing cases follow:
+    /// "der Beamte", "des Beamten", "dem Beamten", "den Beamten".
+    fn adjektivische_formen(&mut self) {
+        if let Some(singular) = self.extra.remove("Singular") {
+            let schwach = format!("{singular}n");
+            let maskulin = self.genus.first().is_some_and(|g| g == "m");
+            let akkusativ = if maskulin { &schwach } else { &singular };
+            self.ergaenze(Kasus::Nominativ, Numerus::Singular, &singular);
+            self.ergaenze(Kasus::Genitiv, Numerus::Singular, &schwach);
+            self.ergaenze(Kasus::Dativ, Numerus::Singular, &schwach);
+            self.ergaenze(Kasus::Akkusativ, Numerus::Singular, akkusativ);
+        }
+        if let Some(plural) = self.extra.remove("Plural") {
+            for kasus in Kasus::ALLE {
+                self.ergaenze(kasus, Numerus::Plural, &plural);
+            }
+        }
+    }
+
+    /// Adds a form for a case and number that has none yet.
+    fn ergaenze(&mut self, kasus: Kasus, numerus: Numerus, text: &str) {
+        if self.form(kasus, numerus).is_empty() {
+            self.formen.push(Form::new(kasus, numerus, text));
+        }
+    }
 }
-impl DativPlural {
-    fn parse(input: &str) -> IResult<&str, Self> {
-        let a = preceded(tag("="), alpha1);
-        let a1 = preceded(tag("Plural"), a);
-        let a2 = preceded(multispace0, a1);
-
-        let b = preceded(tag("Dativ"), a2);
-        let c = preceded(tag("|"), b);
-        let d = preceded(multispace0, c);
-
-        let mut my_parser = map(d, |rest: &str| Self {
-            text: rest.to_string(),
-        });
 
-        my_parser(input)
+/// The field a parameter name stands for. Alternative forms carry a number or a star, e.g.
+/// `Genus 2`, `Nominativ Plural 3` or `Genitiv Singular*`, which is dropped.
+fn schluessel(name: &str) -> String {
+    let mut woerter: Vec<&str> = name.trim_end_matches('*').split_whitespace().collect();
+    if woerter.len() > 1
+        && woerter
+            .last()
+            .is_some_and(|w| w.chars().all(|c| c.is_ascii_digit()))
+    {
+        woerter.pop();
     }
+    woerter.join(" ")
 }
 
+/// One German noun section of a page: the part-of-speech heading it belongs to, its
+/// `{{Deutsch Substantiv Übersicht}}`, the meanings of its `{{Bedeutungen}}` block, its
+/// `{{Worttrennung}}`, its `{{Aussprache}}`, its `{{Beispiele}}`, its `{{Übersetzungen}}`, the
+/// compound its `{{Herkunft}}` describes and the relations of blocks like `{{Synonyme}}`.
+///
+/// Homonyms like "der See" and "die See" have a section each.
 #[derive(PartialEq, Eq, Debug)]
-pub struct AkkusativSingular {
-    pub text: String,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Substantivabschnitt {
+    pub ueberschrift: String,
+    pub uebersicht: DeutschSubstantivUebersicht,
+    pub bedeutungen: Vec<Bedeutung>,
+    pub worttrennung: Worttrennung,
+    pub aussprache: Aussprache,
+    pub beispiele: Vec<Beispiel>,
+    pub uebersetzungen: Vec<Uebersetzung>,
+    pub zusammensetzung: Option<Zusammensetzung>,
+    pub beziehungen: Vec<Beziehung>,
 }
-impl AkkusativSingular {
-    fn parse(input: &str) -> IResult<&str, Self> {
-        let a = preceded(tag("="), alpha1);
-        let a1 = preceded(tag("Singular"), a);
-        let a2 = preceded(multispace0, a1);
-
-        let b = preceded(tag("Akkusativ"), a2);
-        let c = preceded(tag("|"), b);
-        let d = preceded(multispace0, c);
-
-        let mut my_parser = map(d, |rest: &str| Self {
-            text: rest.to_string(),
-        });
 
-        my_parser(input)
+impl Substantivabschnitt {
+    /// Returns every noun table of the German noun and proper-name sections of a page, in the
+    /// order of the page. Sections that [`Substantivabschnitt::lies`] rejects are left out.
+    pub fn alle(seite: &str) -> Vec<Self> {
+        Self::lies(seite).into_iter().flatten().collect()
+    }
+
+    /// Like [`Substantivabschnitt::alle`], but keeps an error for every German noun section
+    /// whose table is missing, cannot be parsed or lacks the gender or the nominative
+    /// singular. Pluralia tantum only need a nominative plural. The offsets of the errors
+    /// point into the page.
+    pub fn lies(seite: &str) -> Vec<Result<Self, Parsefehler>> {
+        let text = seite;
+        let seite = Seite::new(text);
+        seite
+            .sprache("Deutsch")
+            .flat_map(|sprache| &sprache.wortarten)
+            .filter(|wortart| SUBSTANTIVISCH.iter().any(|w| wortart.ist(w)))
+            .map(|wortart| {
+                // A malformed table must not hide the homonyms that follow it.
+                let offset = text.offset(wortart.text);
+                let uebersicht = DeutschSubstantivUebersicht::new(wortart.text)
+                    .map_err(|fehler| fehler.verschoben(offset))?;
+                let ohne = |numerus| uebersicht.form(Kasus::Nominativ, numerus).is_empty();
+                let pflichtfelder = if uebersicht.tantum == Some(Tantum::Plural) {
+                    vec![("Nominativ Plural", ohne(Numerus::Plural))]
+                } else {
+                    vec![
+                        ("Genus", uebersicht.genus.is_empty()),
+                        ("Nominativ Singular", ohne(Numerus::Singular)),
+                    ]
+                };
+                if let Some((feld, _)) = pflichtfelder.into_iter().find(|(_, fehlt)| *fehlt) {
+                    return Err(Parsefehler::FeldFehlt { offset, feld });
+                }
+                Ok(Self {
+                    ueberschrift: wortart.ueberschrift.to_string(),
+                    uebersicht,
+                    bedeutungen: wortart
+                        .unterabschnitt(Abschnittsart::Bedeutungen)
+                        .map(|u| Bedeutung::alle(u.text))
+                        .unwrap_or_default(),
+                    worttrennung: wortart
+                        .unterabschnitt(Abschnittsart::Worttrennung)
+                        .map(|u| Worttrennung::new(u.text))
+                        .unwrap_or_default(),
+                    aussprache: wortart
+                        .unterabschnitt(Abschnittsart::Aussprache)
+                        .map(|u| Aussprache::new(u.text))
+                        .unwrap_or_default(),
+                    beispiele: wortart
+                        .unterabschnitt(Abschnittsart::Beispiele)
+                        .map(|u| Beispiel::alle(u.text))
+                        .unwrap_or_default(),
+                    uebersetzungen: wortart
+                        .unterabschnitt(Abschnittsart::Uebersetzungen)
+                        .map(|u| Uebersetzung::alle(u.text))
+                        .unwrap_or_default(),
+                    zusammensetzung: wortart
+                        .unterabschnitt(Abschnittsart::Herkunft)
+                        .and_then(|u| Zusammensetzung::new(u.text)),
+                    beziehungen: Beziehungsart::ALLE
+                        .into_iter()
+                        .filter_map(|art| {
+                            let unterabschnitt = wortart.unterabschnitt(art.abschnittsart())?;
+                            Some(Beziehung::alle(art, unterabschnitt.text))
+                        })
+                        .flatten()
+                        .collect(),
+                })
+            })
+            .collect()
     }
 }
 
-#[derive(PartialEq, Eq, Debug)]
-pub struct AkkusativPlural {
-    pub text: String,
+/// The parameters of a `{{Deutsch Verb Übersicht}}` template.
+///
+/// Like [`DeutschSubstantivUebersicht`], every field holds all of its forms in the order of the
+/// template. Alternatives are marked with a star, e.g. `|Imperativ Singular*=gehe` next to
+/// `|Imperativ Singular=geh`, or `|Hilfsverb*=haben` next to `|Hilfsverb=sein`.
+#[derive(PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeutschVerbUebersicht {
+    pub praesens_ich: Vec<String>,
+    pub praesens_du: Vec<String>,
+    pub praesens_er_sie_es: Vec<String>,
+    pub praeteritum_ich: Vec<String>,
+    pub konjunktiv_ii_ich: Vec<String>,
+    pub partizip_ii: Vec<String>,
+    pub imperativ_singular: Vec<String>,
+    pub imperativ_plural: Vec<String>,
+    /// `haben` or `sein`.
+    pub hilfsverb: Vec<String>,
+    pub extra: BTreeMap<String, String>,
 }
-impl AkkusativPlural {
-    fn parse(input: &str) -> IResult<&str, Self> {
-        let a = preceded(tag("="), alpha1);
-        let a1 = preceded(tag("Plural"), a);
-        let a2 = preceded(multispace0, a1);
-
-        let b = preceded(tag("Akkusativ"), a2);
-        let c = preceded(tag("|"), b);
-        let d = preceded(multispace0, c);
-
-        let mut my_parser = map(d, |rest: &str| Self {
-            text: rest.to_string(),
-        });
 
-        my_parser(input)
+impl DeutschVerbUebersicht {
+    /// Reads the first `{{Deutsch Verb Übersicht}}` of the given text.
+    pub fn new(input: &str) -> Option<Self> {
+        Vorlage::finde(input, "Deutsch Verb Übersicht").map(|v| Self::from_vorlage(&v))
+    }
+
+    fn from_vorlage(vorlage: &Vorlage) -> Self {
+        let mut uebersicht = Self::default();
+        for parameter in &vorlage.parameter {
+            let Some(name) = parameter.name else {
+                continue;
+            };
+            let text = parameter.text();
+            if text.is_empty() {
+                continue;
+            }
+            let formen = match name.trim_end_matches('*') {
+                "Präsens_ich" => &mut uebersicht.praesens_ich,
+                "Präsens_du" => &mut uebersicht.praesens_du,
+                "Präsens_er, sie, es" => &mut uebersicht.praesens_er_sie_es,
+                "Präteritum_ich" => &mut uebersicht.praeteritum_ich,
+                "Konjunktiv II_ich" => &mut uebersicht.konjunktiv_ii_ich,
+                "Partizip II" => &mut uebersicht.partizip_ii,
+                "Imperativ Singular" => &mut uebersicht.imperativ_singular,
+                "Imperativ Plural" => &mut uebersicht.imperativ_plural,
+                "Hilfsverb" => &mut uebersicht.hilfsverb,
+                _ => {
+                    uebersicht.extra.entry(name.to_string()).or_insert(text);
+                    continue;
+                }
+            };
+            formen.push(text);
+        }
+        uebersicht
     }
 }
 
+/// One German verb section of a page: the infinitive from the language heading, the
+/// part-of-speech heading, its `{{Deutsch Verb Übersicht}}` and the meanings of its
+/// `{{Bedeutungen}}` block.
 #[derive(PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Verbabschnitt {
+    pub infinitiv: String,
+    pub ueberschrift: String,
+    pub uebersicht: DeutschVerbUebersicht,
+    pub bedeutungen: Vec<Bedeutung>,
+}
 
-pub struct DeutschSubstantivUebersicht {
-    pub genus: Genus,
-    pub nominativ_singular: NominativSingular,
-    pub nominativ_plural: NominativPlural,
-    pub genitiv_singular: GenitivSingular,
-    pub genitiv_plural: GenitivPlural,
-    pub dativ_singular: DativSingular,
-    pub dativ_plural: DativPlural,
-    pub akkusativ_singular: AkkusativSingular,
-    pub akkusativ_plural: AkkusativPlural,
+impl Verbabschnitt {
+    /// Returns every verb table of the German verb sections of a page, in the order of the page.
+    pub fn alle(seite: &str) -> Vec<Self> {
+        let seite = Seite::new(seite);
+        let mut abschnitte = Vec::new();
+        for sprache in seite.sprache("Deutsch") {
+            for wortart in sprache.wortarten.iter().filter(|w| w.ist("Verb")) {
+                let Some(uebersicht) = DeutschVerbUebersicht::new(wortart.text) else {
+                    continue;
+                };
+                abschnitte.push(Self {
+                    infinitiv: sprache.wort.to_string(),
+                    ueberschrift: wortart.ueberschrift.to_string(),
+                    uebersicht,
+                    bedeutungen: wortart
+                        .unterabschnitt(Abschnittsart::Bedeutungen)
+                        .map(|u| Bedeutung::alle(u.text))
+                        .unwrap_or_default(),
+                });
+            }
+        }
+        abschnitte
+    }
 }
 
-impl DeutschSubstantivUebersicht {
+/// The parameters of a `{{Deutsch Adjektiv Übersicht}}` template, e.g. `|Positiv=groß`,
+/// `|Komparativ=größer` and `|Superlativ=größten` for "groß – größer – am größten".
+///
+/// Alternatives are marked with a star like in [`DeutschVerbUebersicht`]. Adjectives without
+/// comparison such as "tot" give `—`, which is left out.
+#[derive(PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeutschAdjektivUebersicht {
+    pub positiv: Vec<String>,
+    pub komparativ: Vec<String>,
+    /// The superlative without "am", e.g. `größten`.
+    pub superlativ: Vec<String>,
+    pub extra: BTreeMap<String, String>,
+}
+
+impl DeutschAdjektivUebersicht {
+    /// Reads the first `{{Deutsch Adjektiv Übersicht}}` of the given text.
     pub fn new(input: &str) -> Option<Self> {
-        let parse_wiktionary_seite = DeutschSubstantivUebersicht::parse(input);
-        match parse_wiktionary_seite {
-            Ok((_remaining, overview)) => Some(overview),
-            Err(_e) => None,
+        Vorlage::finde(input, "Deutsch Adjektiv Übersicht").map(|v| Self::from_vorlage(&v))
+    }
+
+    fn from_vorlage(vorlage: &Vorlage) -> Self {
+        let mut uebersicht = Self::default();
+        for parameter in &vorlage.parameter {
+            let Some(name) = parameter.name else {
+                continue;
+            };
+            let text = parameter.text();
+            if fehlt(&text) {
+                continue;
+            }
+            let formen = match name.trim_end_matches('*') {
+                "Positiv" => &mut uebersicht.positiv,
+                "Komparativ" => &mut uebersicht.komparativ,
+                "Superlativ" => &mut uebersicht.superlativ,
+                _ => {
+                    uebersicht.extra.entry(name.to_string()).or_insert(text);
+                    continue;
+                }
+            };
+            formen.push(text);
         }
+        uebersicht
     }
+}
 
-    //{{Deutsch Substantiv Übersicht
-    fn parse_until(input: &str) -> IResult<&str, &str> {
-        let a = preceded(multispace0, tag("Übersicht"));
-        let a1 = preceded(tag("Substantiv"), a);
-        let a2 = preceded(multispace0, a1);
-        let a3 = preceded(tag("Deutsch"), a2);
-        let a4 = preceded(multispace0, a3);
-        let a5 = preceded(tag("{{"), a4);
-        let mut a6 = preceded(multispace0, a5);
-        a6(input)
-    }
-
-    // This is a function that uses the custom tag to drop any input until it finds your parser function
-    fn drop_until_deutsch(input: &str) -> IResult<&str, &str> {
-        let mut recognize_tabelle =
-            recognize(many_till(anychar, DeutschSubstantivUebersicht::parse_until));
-        recognize_tabelle(input)
-        //((let (input, _) = take_until(DeutschSubstantivUebersicht::parse_until)(input)?; // drop the input before your parser function
-        //rest(input) // return the rest of the input
-    }
-
-    fn parse(input: &str) -> IResult<&str, Self> {
-        let b = preceded(multispace0, tag("}}"));
-
-        let c = tuple((
-            Genus::parse,
-            NominativSingular::parse,
-            NominativPlural::parse,
-            GenitivSingular::parse,
-            GenitivPlural::parse,
-            DativSingular::parse,
-            DativPlural::parse,
-            AkkusativSingular::parse,
-            AkkusativPlural::parse,
-        ));
-        let d = delimited(Self::drop_until_deutsch, c, b);
-        let mut my_parser = map(d, |(g, ns, np, gs, gp, ds, dp, a_s, ap)| Self {
-            genus: g,
-            nominativ_singular: ns,
-            nominativ_plural: np,
-            genitiv_singular: gs,
-            genitiv_plural: gp,
-            dativ_singular: ds,
-            dativ_plural: dp,
-            akkusativ_singular: a_s,
-            akkusativ_plural: ap,
-        });
+/// One German adjective section of a page: the word from the language heading, the
+/// part-of-speech heading, its `{{Deutsch Adjektiv Übersicht}}` and the meanings of its
+/// `{{Bedeutungen}}` block.
+#[derive(PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Adjektivabschnitt {
+    pub wort: String,
+    pub ueberschrift: String,
+    pub uebersicht: DeutschAdjektivUebersicht,
+    pub bedeutungen: Vec<Bedeutung>,
+}
 
-        my_parser(input)
+impl Adjektivabschnitt {
+    /// Returns every adjective table of the German adjective sections of a page, in the order
+    /// of the page.
+    pub fn alle(seite: &str) -> Vec<Self> {
+        let seite = Seite::new(seite);
+        let mut abschnitte = Vec::new();
+        for sprache in seite.sprache("Deutsch") {
+            for wortart in sprache.wortarten.iter().filter(|w| w.ist("Adjektiv")) {
+                let Some(uebersicht) = DeutschAdjektivUebersicht::new(wortart.text) else {
+                    continue;
+                };
+                abschnitte.push(Self {
+                    wort: sprache.wort.to_string(),
+                    ueberschrift: wortart.ueberschrift.to_string(),
+                    uebersicht,
+                    bedeutungen: wortart
+                        .unterabschnitt(Abschnittsart::Bedeutungen)
+                        .map(|u| Bedeutung::alle(u.text))
+                        .unwrap_or_default(),
+                });
+            }
+        }
+        abschnitte
     }
 }
 
@@ -281,39 +660,82 @@ impl DeutschSubstantivUebersicht {
 mod tests {
     use super::*;
 
+    fn uebersicht(parameter: &str) -> DeutschSubstantivUebersicht {
+        DeutschSubstantivUebersicht::new(&format!(
+            "{{{{Deutsch Substantiv Übersicht{parameter}}}}}"
+        ))
+        .unwrap()
+    }
+
     #[test]
-    fn genus() {
-        let foo = Genus::parse("|Genus=f");
-        let verify = Ok((
-            "",
-            Genus {
-                genus: String::from("f"),
-            },
-        ));
-        assert_eq!(foo, verify);
+    fn wert_unicode() {
+        let foo = uebersicht(
+            "|Nominativ Singular=Kopfnüsse|Genitiv Singular=Straße\n|Dativ Singular=Äpfel",
+        );
+        assert_eq!(
+            foo.form(Kasus::Nominativ, Numerus::Singular)[0],
+            "Kopfnüsse"
+        );
+        assert_eq!(foo.form(Kasus::Genitiv, Numerus::Singular)[0], "Straße");
+        assert_eq!(foo.form(Kasus::Dativ, Numerus::Singular)[0], "Äpfel");
+        let foo = uebersicht("|Nominativ Singular=E-Mail|Nominativ Plural=Schwarzes Brett \t\r\n");
+        assert_eq!(foo.form(Kasus::Nominativ, Numerus::Singular)[0], "E-Mail");
+        assert_eq!(
+            foo.form(Kasus::Nominativ, Numerus::Plural)[0],
+            "Schwarzes Brett"
+        );
+        let foo = uebersicht("|Nominativ Singular=\n|Genus=f|Dativ Singular=   \n");
+        assert!(foo.form(Kasus::Nominativ, Numerus::Singular).is_empty());
+        assert!(foo.form(Kasus::Dativ, Numerus::Singular).is_empty());
+    }
 
-        let foo = Genus::parse("  \t\n\r |Genus=f");
-        assert_eq!(foo, verify);
+    #[test]
+    fn genus() {
+        let verify = vec!["f"];
+        assert_eq!(uebersicht("|Genus=f").genus, verify);
+        assert_eq!(uebersicht("  \t\n\r |Genus=f").genus, verify);
     }
 
     #[test]
     fn nominativ_singular() {
-        let foo = NominativSingular::parse("|Nominativ Singular=Kopfschmerztablette");
-        let verify = Ok((
-            "",
-            NominativSingular {
-                text: String::from("Kopfschmerztablette"),
-            },
-        ));
-        assert_eq!(foo, verify);
+        let verify = vec!["Kopfschmerztablette"];
+        let foo = uebersicht("|Nominativ Singular=Kopfschmerztablette");
+        assert_eq!(foo.form(Kasus::Nominativ, Numerus::Singular), verify);
+        let foo = uebersicht("  \t\n\r |Nominativ Singular=Kopfschmerztablette");
+        assert_eq!(foo.form(Kasus::Nominativ, Numerus::Singular), verify);
+    }
 
-        let foo = NominativSingular::parse("  \t\n\r |Nominativ Singular=Kopfschmerztablette");
-        assert_eq!(foo, verify);
+    #[test]
+    fn nominativ_plural_umlaut() {
+        let foo = uebersicht("\n|Nominativ Plural=Kopfnüsse\n|Genitiv Singular=Kopfnuss");
+        assert_eq!(
+            foo.form(Kasus::Nominativ, Numerus::Plural),
+            vec!["Kopfnüsse"]
+        );
+        assert_eq!(foo.form(Kasus::Genitiv, Numerus::Singular)[0], "Kopfnuss");
+    }
+
+    #[test]
+    fn dativ_plural_trailing_whitespace() {
+        let foo = uebersicht("|Dativ Plural=Straßen  \r\n");
+        assert_eq!(foo.form(Kasus::Dativ, Numerus::Plural), vec!["Straßen"]);
+    }
+
+    #[test]
+    fn verschachteltes_markup() {
+        let foo = uebersicht(
+            "|Nominativ Plural=[[Seen]]<ref>{{Ref-Duden|See|Seite=1}}</ref>\n|Bild=See.jpg|mini|[[Bodensee|der Bodensee]]",
+        );
+        assert_eq!(foo.form(Kasus::Nominativ, Numerus::Plural)[0], "Seen");
+        assert_eq!(
+            foo.extra,
+            BTreeMap::from([("Bild".to_string(), "See.jpg".to_string())])
+        );
     }
 
     #[test]
     fn ubersicht() {
-        let foo = DeutschSubstantivUebersicht::parse(
+        let foo = DeutschSubstantivUebersicht::new(
             "   {{Deutsch Substantiv Übersicht
             |Genus=f
             |Nominativ Singular=Kopfschmerztablette
@@ -326,44 +748,28 @@ mod tests {
             |Akkusativ Plural=Kopfschmerztabletten
             }}",
         );
-        let verify = Ok((
-            "",
-            DeutschSubstantivUebersicht {
-                genus: Genus {
-                    genus: "f".to_string(),
-                },
-                nominativ_singular: NominativSingular {
-                    text: "Kopfschmerztablette".to_string(),
-                },
-                nominativ_plural: NominativPlural {
-                    text: "Kopfschmerztabletten".to_string(),
-                },
-                genitiv_singular: GenitivSingular {
-                    text: "Kopfschmerztablette".to_string(),
-                },
-                genitiv_plural: GenitivPlural {
-                    text: "Kopfschmerztabletten".to_string(),
-                },
-                dativ_singular: DativSingular {
-                    text: "Kopfschmerztablette".to_string(),
-                },
-                dativ_plural: DativPlural {
-                    text: "Kopfschmerztabletten".to_string(),
-                },
-                akkusativ_singular: AkkusativSingular {
-                    text: "Kopfschmerztablette".to_string(),
-                },
-                akkusativ_plural: AkkusativPlural {
-                    text: "Kopfschmerztabletten".to_string(),
-                },
-            },
-        ));
+        let verify = Ok(DeutschSubstantivUebersicht {
+            art: Substantivart::Substantiv,
+            tantum: None,
+            genus: vec!["f".to_string()],
+            formen: vec![
+                Form::new(Kasus::Nominativ, Numerus::Singular, "Kopfschmerztablette"),
+                Form::new(Kasus::Nominativ, Numerus::Plural, "Kopfschmerztabletten"),
+                Form::new(Kasus::Genitiv, Numerus::Singular, "Kopfschmerztablette"),
+                Form::new(Kasus::Genitiv, Numerus::Plural, "Kopfschmerztabletten"),
+                Form::new(Kasus::Dativ, Numerus::Singular, "Kopfschmerztablette"),
+                Form::new(Kasus::Dativ, Numerus::Plural, "Kopfschmerztabletten"),
+                Form::new(Kasus::Akkusativ, Numerus::Singular, "Kopfschmerztablette"),
+                Form::new(Kasus::Akkusativ, Numerus::Plural, "Kopfschmerztabletten"),
+            ],
+            extra: BTreeMap::new(),
+        });
         assert_eq!(foo, verify);
     }
 
     #[test]
     fn alles() {
-        let foo = DeutschSubstantivUebersicht::parse(
+        let foo = DeutschSubstantivUebersicht::new(
             "abcde ds fdjfdsl flkdsj  {{Deutsch Substantiv Übersicht
             |Genus=f
             |Nominativ Singular=Kopfschmerztablette
@@ -376,40 +782,410 @@ mod tests {
             |Akkusativ Plural=Kopfschmerztabletten
             }}",
         );
-        let verify = Ok((
-            "",
-            DeutschSubstantivUebersicht {
-                genus: Genus {
-                    genus: "f".to_string(),
-                },
-                nominativ_singular: NominativSingular {
-                    text: "Kopfschmerztablette".to_string(),
-                },
-                nominativ_plural: NominativPlural {
-                    text: "Kopfschmerztabletten".to_string(),
-                },
-                genitiv_singular: GenitivSingular {
-                    text: "Kopfschmerztablette".to_string(),
-                },
-                genitiv_plural: GenitivPlural {
-                    text: "Kopfschmerztabletten".to_string(),
-                },
-                dativ_singular: DativSingular {
-                    text: "Kopfschmerztablette".to_string(),
-                },
-                dativ_plural: DativPlural {
-                    text: "Kopfschmerztabletten".to_string(),
-                },
-                akkusativ_singular: AkkusativSingular {
-                    text: "Kopfschmerztablette".to_string(),
-                },
-                akkusativ_plural: AkkusativPlural {
-                    text: "Kopfschmerztabletten".to_string(),
-                },
-            },
-        ));
+        let verify = Ok(DeutschSubstantivUebersicht {
+            art: Substantivart::Substantiv,
+            tantum: None,
+            genus: vec!["f".to_string()],
+            formen: vec![
+                Form::new(Kasus::Nominativ, Numerus::Singular, "Kopfschmerztablette"),
+                Form::new(Kasus::Nominativ, Numerus::Plural, "Kopfschmerztabletten"),
+                Form::new(Kasus::Genitiv, Numerus::Singular, "Kopfschmerztablette"),
+                Form::new(Kasus::Genitiv, Numerus::Plural, "Kopfschmerztabletten"),
+                Form::new(Kasus::Dativ, Numerus::Singular, "Kopfschmerztablette"),
+                Form::new(Kasus::Dativ, Numerus::Plural, "Kopfschmerztabletten"),
+                Form::new(Kasus::Akkusativ, Numerus::Singular, "Kopfschmerztablette"),
+                Form::new(Kasus::Akkusativ, Numerus::Plural, "Kopfschmerztabletten"),
+            ],
+            extra: BTreeMap::new(),
+        });
         assert_eq!(foo, verify);
     }
+
+    #[test]
+    fn ubersicht_beliebige_reihenfolge() {
+        let foo = DeutschSubstantivUebersicht::new(
+            "{{Deutsch Substantiv Übersicht
+            |Nominativ Plural=Bäume
+            |Genus=m
+            |Bild=Baum 1.jpg|mini|1|ein [[Laubbaum]] [1]
+            |Nominativ Singular=Baum
+            |Genitiv Singular*=Baums
+            |Dativ Plural=
+            }}",
+        )
+        .unwrap();
+        assert_eq!(foo.genus, vec!["m"]);
+        assert_eq!(foo.form(Kasus::Nominativ, Numerus::Singular), vec!["Baum"]);
+        assert_eq!(foo.form(Kasus::Nominativ, Numerus::Plural), vec!["Bäume"]);
+        assert_eq!(foo.form(Kasus::Genitiv, Numerus::Singular), vec!["Baums"]);
+        assert!(foo.form(Kasus::Dativ, Numerus::Plural).is_empty());
+        assert!(foo.form(Kasus::Akkusativ, Numerus::Plural).is_empty());
+        assert_eq!(
+            foo.extra,
+            BTreeMap::from([("Bild".to_string(), "Baum 1.jpg".to_string())])
+        );
+    }
+
+    #[test]
+    fn genus_nummeriert() {
+        let foo = uebersicht("\n|Genus 2=n|Genus*=m");
+        assert_eq!(foo.genus, vec!["n", "m"]);
+        assert!(foo.extra.is_empty());
+    }
+
+    #[test]
+    fn ubersicht_mehrere_formen() {
+        let foo = DeutschSubstantivUebersicht::new(
+            "{{Deutsch Substantiv Übersicht
+            |Genus 1=m
+            |Genus 2=n
+            |Nominativ Singular 1=Joghurt
+            |Nominativ Singular 2=Joghurt
+            |Nominativ Plural 1=Joghurts
+            |Nominativ Plural 2=Joghurt
+            |Nominativ Plural 3=
+            }}",
+        )
+        .unwrap();
+        assert_eq!(foo.genus, ["m", "n"]);
+        assert_eq!(
+            foo.form(Kasus::Nominativ, Numerus::Plural),
+            ["Joghurts", "Joghurt"]
+        );
+        assert_eq!(foo.form(Kasus::Nominativ, Numerus::Singular).len(), 2);
+        assert!(foo.extra.is_empty());
+    }
+
+    #[test]
+    fn alle_abschnitte_nach_fehlerhafter_tabelle() {
+        let abschnitte = Substantivabschnitt::alle(
+            "== Band ({{Sprache|Deutsch}}) ==
+=== {{Wortart|Substantiv|Deutsch}}, {{m}} ===
+{{Deutsch Substantiv Übersicht
+|Genus=m
+{{kaputt}}
+=== {{Wortart|Substantiv|Deutsch}}, {{n}} ===
+{{Deutsch Substantiv Übersicht
+|Genus=n
+|Nominativ Singular=Band
+}}
+{{Bedeutungen}}
+:[1] [[Streifen]] aus [[Stoff]]
+:[2] [[Buch]] einer [[Reihe]]
+
+{{Beispiele}}
+:[1] Sie trägt ein ''Band'' im Haar.",
+        );
+        assert_eq!(abschnitte.len(), 1);
+        assert_eq!(
+            abschnitte[0].ueberschrift,
+            "{{Wortart|Substantiv|Deutsch}}, {{n}}"
+        );
+        assert_eq!(
+            abschnitte[0]
+                .uebersicht
+                .form(Kasus::Nominativ, Numerus::Singular)[0],
+            "Band"
+        );
+        let bedeutungen: Vec<(&str, &str)> = abschnitte[0]
+            .bedeutungen
+            .iter()
+            .map(|b| (b.nummer.as_str(), b.text.as_str()))
+            .collect();
+        assert_eq!(
+            bedeutungen,
+            [("1", "Streifen aus Stoff"), ("2", "Buch einer Reihe")]
+        );
+    }
+
+    #[test]
+    fn alle_nur_deutsche_substantive() {
+        let abschnitte = Substantivabschnitt::alle(
+            "== Rot ({{Sprache|Deutsch}}) ==
+=== {{Wortart|Adjektiv|Deutsch}} ===
+{{Deutsch Substantiv Übersicht
+|Genus=n
+|Nominativ Singular=Falsch
+}}
+=== {{Wortart|Substantiv|Deutsch}}, {{n}} ===
+{{Deutsch Substantiv Übersicht
+|Genus=n
+|Nominativ Singular=Rot
+}}
+== rot ({{Sprache|Niederländisch}}) ==
+=== {{Wortart|Substantiv|Niederländisch}} ===
+{{Deutsch Substantiv Übersicht
+|Genus=n
+|Nominativ Singular=Falsch
+}}",
+        );
+        assert_eq!(abschnitte.len(), 1);
+        assert_eq!(
+            abschnitte[0]
+                .uebersicht
+                .form(Kasus::Nominativ, Numerus::Singular)[0],
+            "Rot"
+        );
+        assert!(abschnitte[0].bedeutungen.is_empty());
+    }
+
+    #[test]
+    fn ubersicht_ohne_parameter() {
+        let foo = DeutschSubstantivUebersicht::new("{{Deutsch Substantiv Übersicht}}");
+        assert_eq!(foo, Ok(DeutschSubstantivUebersicht::default()));
+        assert_eq!(
+            DeutschSubstantivUebersicht::new("{{Deutsch Verb Übersicht}}"),
+            Err(Parsefehler::KeineTabelle)
+        );
+    }
+
+    #[test]
+    fn verb_uebersicht() {
+        let foo = DeutschVerbUebersicht::new(
+            "{{Deutsch Verb Übersicht
+|Präsens_ich=gehe
+|Präsens_du=gehst
+|Präsens_er, sie, es=geht
+|Präteritum_ich=ging
+|Partizip II=gegangen
+|Konjunktiv II_ich=ginge
+|Imperativ Singular=geh
+|Imperativ Singular*=gehe
+|Imperativ Plural=geht
+|Hilfsverb=sein
+|Bild=Gehen.jpg
+}}",
+        )
+        .unwrap();
+        assert_eq!(foo.praesens_er_sie_es, ["geht"]);
+        assert_eq!(foo.praeteritum_ich, ["ging"]);
+        assert_eq!(foo.partizip_ii, ["gegangen"]);
+        assert_eq!(foo.konjunktiv_ii_ich, ["ginge"]);
+        assert_eq!(foo.imperativ_singular, ["geh", "gehe"]);
+        assert_eq!(foo.hilfsverb, ["sein"]);
+        assert_eq!(
+            foo.extra,
+            BTreeMap::from([("Bild".to_string(), "Gehen.jpg".to_string())])
+        );
+    }
+
+    #[test]
+    fn alle_verben() {
+        let abschnitte = Verbabschnitt::alle(
+            "== schwimmen ({{Sprache|Deutsch}}) ==
+=== {{Wortart|Verb|Deutsch}} ===
+{{Deutsch Verb Übersicht
+|Präsens_ich=schwimme
+|Partizip II=geschwommen
+|Hilfsverb=sein
+|Hilfsverb*=haben
+}}
+{{Bedeutungen}}
+:[1] sich im [[Wasser]] [[fortbewegen]]
+=== {{Wortart|Substantiv|Deutsch}}, {{n}} ===
+{{Deutsch Substantiv Übersicht
+|Genus=n
+|Nominativ Singular=Schwimmen
+}}",
+        );
+        assert_eq!(abschnitte.len(), 1);
+        assert_eq!(abschnitte[0].infinitiv, "schwimmen");
+        assert_eq!(abschnitte[0].uebersicht.hilfsverb, ["sein", "haben"]);
+        assert_eq!(
+            abschnitte[0].bedeutungen[0].text,
+            "sich im Wasser fortbewegen"
+        );
+    }
+
+    #[test]
+    fn adjektiv_uebersicht() {
+        let foo = DeutschAdjektivUebersicht::new(
+            "{{Deutsch Adjektiv Übersicht
+|Positiv=groß
+|Komparativ=größer
+|Superlativ=größten
+|Bild=Elefant.jpg
+}}",
+        )
+        .unwrap();
+        assert_eq!(foo.positiv, ["groß"]);
+        assert_eq!(foo.komparativ, ["größer"]);
+        assert_eq!(foo.superlativ, ["größten"]);
+        assert_eq!(foo.extra["Bild"], "Elefant.jpg");
+
+        let foo = uebersicht_adjektiv("|Positiv=tot|Komparativ=—|Superlativ=—");
+        assert_eq!(foo.positiv, ["tot"]);
+        assert!(foo.komparativ.is_empty());
+        assert!(foo.superlativ.is_empty());
+
+        let foo = uebersicht_adjektiv("|Positiv=gesund|Komparativ=gesünder|Komparativ*=gesunder");
+        assert_eq!(foo.komparativ, ["gesünder", "gesunder"]);
+    }
+
+    fn uebersicht_adjektiv(parameter: &str) -> DeutschAdjektivUebersicht {
+        DeutschAdjektivUebersicht::new(&format!("{{{{Deutsch Adjektiv Übersicht{parameter}}}}}"))
+            .unwrap()
+    }
+
+    #[test]
+    fn alle_adjektive() {
+        let abschnitte = Adjektivabschnitt::alle(
+            "== groß ({{Sprache|Deutsch}}) ==
+=== {{Wortart|Adjektiv|Deutsch}} ===
+{{Deutsch Adjektiv Übersicht
+|Positiv=groß
+|Komparativ=größer
+|Superlativ=größten
+}}
+{{Bedeutungen}}
+:[1] von beträchtlicher [[Ausdehnung]]",
+        );
+        assert_eq!(abschnitte.len(), 1);
+        assert_eq!(abschnitte[0].wort, "groß");
+        assert_eq!(abschnitte[0].uebersicht.superlativ, ["größten"]);
+        assert_eq!(abschnitte[0].bedeutungen.len(), 1);
+    }
+
+    #[test]
+    fn adjektivisch() {
+        let foo = DeutschSubstantivUebersicht::new(
+            "{{Deutsch adjektivisch Übersicht
+|Genus=m
+|Singular=Beamte
+|Plural=Beamten
+}}",
+        )
+        .unwrap();
+        assert_eq!(foo.art, Substantivart::Adjektivisch);
+        assert_eq!(foo.form(Kasus::Nominativ, Numerus::Singular)[0], "Beamte");
+        assert_eq!(foo.form(Kasus::Genitiv, Numerus::Singular)[0], "Beamten");
+        assert_eq!(foo.form(Kasus::Akkusativ, Numerus::Singular)[0], "Beamten");
+        assert_eq!(foo.form(Kasus::Dativ, Numerus::Plural)[0], "Beamten");
+        assert!(foo.extra.is_empty());
+
+        let foo = DeutschSubstantivUebersicht::new(
+            "{{Deutsch adjektivisch Übersicht|Genus=f|Singular=Angestellte|Plural=Angestellten}}",
+        )
+        .unwrap();
+        assert_eq!(
+            foo.form(Kasus::Akkusativ, Numerus::Singular)[0],
+            "Angestellte"
+        );
+    }
+
+    #[test]
+    fn eigennamen() {
+        let foo = DeutschSubstantivUebersicht::new(
+            "{{Deutsch Vorname Übersicht m|Nominativ Singular=Paul|Genitiv Singular=Pauls}}",
+        )
+        .unwrap();
+        assert_eq!(foo.art, Substantivart::Eigenname);
+        assert_eq!(foo.genus[0], "m");
+
+        let abschnitte = Substantivabschnitt::alle(
+            "== Schweiz ({{Sprache|Deutsch}}) ==
+=== {{Wortart|Toponym|Deutsch}}, {{f}} ===
+{{Deutsch Toponym Übersicht
+|Genus=f
+|Nominativ Singular=Schweiz
+|Nominativ Plural=—
+}}",
+        );
+        assert_eq!(abschnitte.len(), 1);
+        assert_eq!(abschnitte[0].uebersicht.art, Substantivart::Toponym);
+        assert_eq!(abschnitte[0].uebersicht.genus[0], "f");
+    }
+
+    #[test]
+    fn tantum() {
+        let foo = uebersicht(
+            "|Genus=m|Nominativ Singular=Durst|Nominativ Plural=—|Genitiv Plural=—|Dativ Singular=Durst",
+        );
+        assert_eq!(foo.tantum, Some(Tantum::Singular));
+        assert!(foo.form(Kasus::Nominativ, Numerus::Plural).is_empty());
+        assert!(foo.form(Kasus::Genitiv, Numerus::Plural).is_empty());
+        assert!(foo.extra.is_empty());
+
+        let foo = uebersicht("|Genus=0|Nominativ Singular=—|Nominativ Plural=Leute");
+        assert_eq!(foo.tantum, Some(Tantum::Plural));
+        assert!(foo.genus.is_empty());
+        assert!(foo.form(Kasus::Nominativ, Numerus::Singular).is_empty());
+
+        assert_eq!(uebersicht("|Genus=m|Nominativ Singular=Baum").tantum, None);
+    }
+
+    #[test]
+    fn parsefehler() {
+        let text = "{{Deutsch Substantiv Übersicht\n|Genus=m\n|Nominativ Singular=Baum\n";
+        assert_eq!(
+            DeutschSubstantivUebersicht::new(text),
+            Err(Parsefehler::Unlesbar {
+                offset: text.len(),
+                grund: "Tag".to_string(),
+            })
+        );
+        assert_eq!(
+            DeutschSubstantivUebersicht::new("<!-- {{Deutsch Substantiv Übersicht|Genus=m}} -->"),
+            Err(Parsefehler::KeineTabelle)
+        );
+        assert_eq!(
+            DeutschSubstantivUebersicht::new("{{Deutsch Substantiv Übersicht|Genus=x}}"),
+            Err(Parsefehler::UngueltigerWert {
+                offset: 32,
+                feld: "Genus".to_string(),
+                wert: "x".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn abschnitte_lesen() {
+        let seite = "== Baum ({{Sprache|Deutsch}}) ==
+=== {{Wortart|Substantiv|Deutsch}}, {{m}} ===
+{{Deutsch Substantiv Übersicht
+|Genus=m
+}}
+=== {{Wortart|Substantiv|Deutsch}}, {{m}} ===
+{{Deutsch Substantiv Übersicht
+|Genus=m
+|Nominativ Singular=Baum
+}}
+=== {{Wortart|Substantiv|Deutsch}} ===
+{{Deutsch Substantiv Übersicht Neu}}";
+        let abschnitte = Substantivabschnitt::lies(seite);
+        assert_eq!(abschnitte.len(), 3);
+        let offset = seite.find("{{Deutsch").unwrap();
+        assert_eq!(
+            abschnitte[0],
+            Err(Parsefehler::FeldFehlt {
+                offset,
+                feld: "Nominativ Singular",
+            })
+        );
+        assert!(abschnitte[1].is_ok());
+        assert_eq!(abschnitte[2], Err(Parsefehler::KeineTabelle));
+        assert_eq!(Substantivabschnitt::alle(seite).len(), 1);
+    }
+
+    #[test]
+    fn alle_formen() {
+        let foo = uebersicht("|Genus=f|Dativ Plural=Nüssen|Nominativ Singular=Nuss|Bild=Nuss.jpg");
+        let formen: Vec<_> = foo.alle_formen().collect();
+        assert_eq!(formen.len(), 8);
+        assert_eq!(
+            formen[0],
+            (Kasus::Nominativ, Numerus::Singular, vec!["Nuss"])
+        );
+        assert_eq!(formen[5], (Kasus::Dativ, Numerus::Plural, vec!["Nüssen"]));
+        assert!(formen[7].2.is_empty());
+        assert_eq!(
+            foo.formen,
+            [
+                Form::new(Kasus::Dativ, Numerus::Plural, "Nüssen"),
+                Form::new(Kasus::Nominativ, Numerus::Singular, "Nuss"),
+            ]
+        );
+    }
 }
 
 /*
@@ -0,0 +1,140 @@
+//! Sorts nouns by how they form the plural, e.g. "Tag – Tage" takes "-e" and "Haus – Häuser"
+//! takes "-er" with an umlaut.
+
+/// The ending that turns the singular into the plural.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Pluralendung {
+    /// "Tag – Tage".
+    E,
+    /// "Kind – Kinder".
+    Er,
+    /// "-en" or "-n": "Frau – Frauen", "Blume – Blumen", "Lehrerin – Lehrerinnen".
+    En,
+    /// "Auto – Autos".
+    S,
+    /// No ending: "Lehrer – Lehrer", "Apfel – Äpfel".
+    Null,
+    /// Anything else, e.g. "Museum – Museen" or "Kaufmann – Kaufleute".
+    Sonstige,
+}
+
+impl Pluralendung {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::E => "e",
+            Self::Er => "er",
+            Self::En => "en",
+            Self::S => "s",
+            Self::Null => "null",
+            Self::Sonstige => "sonstige",
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pluralklasse {
+    pub endung: Pluralendung,
+    /// Whether the stem takes an umlaut, like "Haus – Häuser". Always `false` for
+    /// [`Pluralendung::Sonstige`].
+    pub umlaut: bool,
+}
+
+impl Pluralklasse {
+    /// The class of a plural, or `None` without one.
+    pub fn bestimme(singular: &str, plural: &str) -> Option<Self> {
+        if singular.is_empty() || plural.is_empty() {
+            return None;
+        }
+        // "-innen" doubles the "n" of "-in".
+        if singular.ends_with("in") && plural.strip_suffix("nen") == Some(singular) {
+            return Some(Self {
+                endung: Pluralendung::En,
+                umlaut: false,
+            });
+        }
+        let endungen = [
+            ("er", Pluralendung::Er),
+            ("en", Pluralendung::En),
+            ("n", Pluralendung::En),
+            ("e", Pluralendung::E),
+            ("s", Pluralendung::S),
+            ("", Pluralendung::Null),
+        ];
+        for (endung, klasse) in endungen {
+            let Some(stamm) = plural.strip_suffix(endung) else {
+                continue;
+            };
+            if stamm == singular {
+                return Some(Self {
+                    endung: klasse,
+                    umlaut: false,
+                });
+            }
+            if ohne_umlaut(stamm) == ohne_umlaut(singular) {
+                return Some(Self {
+                    endung: klasse,
+                    umlaut: true,
+                });
+            }
+        }
+        Some(Self {
+            endung: Pluralendung::Sonstige,
+            umlaut: false,
+        })
+    }
+
+    /// The name stored in the `pluralklasse` column, e.g. "er_umlaut" for "Haus – Häuser".
+    pub fn name(self) -> &'static str {
+        match (self.endung, self.umlaut) {
+            (Pluralendung::E, true) => "e_umlaut",
+            (Pluralendung::Er, true) => "er_umlaut",
+            (Pluralendung::En, true) => "en_umlaut",
+            (Pluralendung::S, true) => "s_umlaut",
+            (Pluralendung::Null, true) => "null_umlaut",
+            (endung, _) => endung.name(),
+        }
+    }
+}
+
+fn ohne_umlaut(wort: &str) -> String {
+    wort.chars()
+        .map(|c| match c {
+            'ä' => 'a',
+            'ö' => 'o',
+            'ü' => 'u',
+            'Ä' => 'A',
+            'Ö' => 'O',
+            'Ü' => 'U',
+            c => c,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn name(singular: &str, plural: &str) -> &'static str {
+        Pluralklasse::bestimme(singular, plural).unwrap().name()
+    }
+
+    #[test]
+    fn klassen() {
+        assert_eq!(name("Tag", "Tage"), "e");
+        assert_eq!(name("Baum", "Bäume"), "e_umlaut");
+        assert_eq!(name("Kind", "Kinder"), "er");
+        assert_eq!(name("Haus", "Häuser"), "er_umlaut");
+        assert_eq!(name("Frau", "Frauen"), "en");
+        assert_eq!(name("Blume", "Blumen"), "en");
+        assert_eq!(name("Lehrerin", "Lehrerinnen"), "en");
+        assert_eq!(name("Auto", "Autos"), "s");
+        assert_eq!(name("Lehrer", "Lehrer"), "null");
+        assert_eq!(name("Apfel", "Äpfel"), "null_umlaut");
+        assert_eq!(name("Mutter", "Mütter"), "null_umlaut");
+        assert_eq!(name("Bär", "Bären"), "en");
+        assert_eq!(name("Museum", "Museen"), "sonstige");
+        assert_eq!(Pluralklasse::bestimme("Schwimmen", ""), None);
+    }
+}
@@ -0,0 +1,388 @@
+//! Splits the wikitext of a page into a tree of sections.
+//!
+//! A page of the German Wiktionary looks like this:
+//!
+//! ```text
+//! == Kopfnuss ({{Sprache|Deutsch}}) ==              <- Sprachabschnitt
+//! === {{Wortart|Substantiv|Deutsch}}, {{f}} ===      <- Wortartabschnitt
+//! {{Deutsch Substantiv Übersicht                     <- Wortartabschnitt::text
+//! …
+//! }}
+//! {{Bedeutungen}}                                    <- Unterabschnitt
+//! :[1] …
+//! ==== {{Übersetzungen}} ====                        <- Unterabschnitt
+//! …
+//! ```
+//!
+//! Extractors look up the section they need in this tree instead of searching the raw text,
+//! so they never pick up a table of another language or part of speech.
+
+use nom::bytes::complete::{tag, take_till1, take_until};
+use nom::character::complete::space0;
+use nom::combinator::{all_consuming, map};
+use nom::sequence::{delimited, preceded, terminated, tuple};
+use nom::IResult;
+
+/// The kind of a subsection, named after the template that starts it.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Abschnittsart<'a> {
+    Aussprache,
+    Bedeutungen,
+    Beispiele,
+    Gegenwoerter,
+    Herkunft,
+    Oberbegriffe,
+    Referenzen,
+    SinnverwandteWoerter,
+    Synonyme,
+    Uebersetzungen,
+    Unterbegriffe,
+    Worttrennung,
+    Sonstiges(&'a str),
+}
+
+impl<'a> From<&'a str> for Abschnittsart<'a> {
+    fn from(name: &'a str) -> Self {
+        match name {
+            "Aussprache" => Self::Aussprache,
+            "Bedeutungen" => Self::Bedeutungen,
+            "Beispiele" => Self::Beispiele,
+            "Gegenwörter" => Self::Gegenwoerter,
+            "Herkunft" => Self::Herkunft,
+            "Oberbegriffe" => Self::Oberbegriffe,
+            "Referenzen" => Self::Referenzen,
+            "Sinnverwandte Wörter" => Self::SinnverwandteWoerter,
+            "Synonyme" => Self::Synonyme,
+            "Übersetzungen" => Self::Uebersetzungen,
+            "Unterbegriffe" => Self::Unterbegriffe,
+            "Worttrennung" => Self::Worttrennung,
+            sonstiges => Self::Sonstiges(sonstiges),
+        }
+    }
+}
+
+/// A subsection such as `{{Bedeutungen}}` or `==== {{Übersetzungen}} ====`. The text starts
+/// after the line that opens the subsection.
+#[derive(PartialEq, Eq, Debug)]
+pub struct Unterabschnitt<'a> {
+    pub art: Abschnittsart<'a>,
+    pub text: &'a str,
+}
+
+/// A part-of-speech section, e.g. `=== {{Wortart|Substantiv|Deutsch}}, {{f}} ===`.
+#[derive(PartialEq, Eq, Debug)]
+pub struct Wortartabschnitt<'a> {
+    /// The heading without the surrounding `=`.
+    pub ueberschrift: &'a str,
+    /// Every `{{Wortart|…}}` of the heading, e.g. `Substantiv` or `Toponym`.
+    pub wortarten: Vec<&'a str>,
+    /// The genders given in the heading, e.g. `m` for `{{m}}`.
+    pub genera: Vec<&'a str>,
+    /// The text between the heading and the first subsection, which holds the overview table.
+    pub text: &'a str,
+    pub unterabschnitte: Vec<Unterabschnitt<'a>>,
+}
+
+impl<'a> Wortartabschnitt<'a> {
+    /// Whether the heading names the given part of speech.
+    pub fn ist(&self, wortart: &str) -> bool {
+        self.wortarten.contains(&wortart)
+    }
+
+    /// The first subsection of the given kind.
+    pub fn unterabschnitt(&self, art: Abschnittsart) -> Option<&Unterabschnitt<'a>> {
+        self.unterabschnitte.iter().find(|u| u.art == art)
+    }
+}
+
+/// A language section, e.g. `== Kopfnuss ({{Sprache|Deutsch}}) ==`.
+#[derive(PartialEq, Eq, Debug)]
+pub struct Sprachabschnitt<'a> {
+    pub wort: &'a str,
+    pub sprache: &'a str,
+    pub wortarten: Vec<Wortartabschnitt<'a>>,
+}
+
+/// The section tree of a page.
+#[derive(PartialEq, Eq, Debug, Default)]
+pub struct Seite<'a> {
+    pub sprachen: Vec<Sprachabschnitt<'a>>,
+}
+
+impl<'a> Seite<'a> {
+    pub fn new(text: &'a str) -> Self {
+        let mut seite = Self::default();
+        // Byte offset where the text of the innermost open section begins.
+        let mut beginn = 0;
+        let mut offset = 0;
+        for zeile in text.split_inclusive('\n') {
+            let ende = offset;
+            offset += zeile.len();
+            let zeile = zeile.trim_end();
+
+            let neuer_abschnitt = match ebene(zeile) {
+                2 => Some(Zeile::Sprache(
+                    sprachueberschrift(zeile).ok().map(|(_, s)| s),
+                )),
+                3 => Some(Zeile::Wortart(zeile)),
+                4.. => unterabschnittsueberschrift(zeile)
+                    .ok()
+                    .map(|(_, name)| Zeile::Unterabschnitt(name)),
+                _ => textbaustein(zeile)
+                    .ok()
+                    .map(|(_, name)| Zeile::Unterabschnitt(name)),
+            };
+            let Some(neuer_abschnitt) = neuer_abschnitt else {
+                continue;
+            };
+
+            seite.schliesse_ab(&text[beginn..ende]);
+            beginn = offset;
+            match neuer_abschnitt {
+                Zeile::Sprache(Some((wort, sprache))) => seite.sprachen.push(Sprachabschnitt {
+                    wort,
+                    sprache,
+                    wortarten: Vec::new(),
+                }),
+                // Text below a heading that is not a language section belongs to no language.
+                Zeile::Sprache(None) => seite.sprachen.push(Sprachabschnitt {
+                    wort: "",
+                    sprache: "",
+                    wortarten: Vec::new(),
+                }),
+                Zeile::Wortart(zeile) => {
+                    if let Some(sprache) = seite.sprachen.last_mut() {
+                        sprache.wortarten.push(wortartueberschrift(zeile));
+                    }
+                }
+                Zeile::Unterabschnitt(name) => {
+                    if let Some(wortart) = seite.letzte_wortart() {
+                        wortart.unterabschnitte.push(Unterabschnitt {
+                            art: Abschnittsart::from(name),
+                            text: "",
+                        });
+                    }
+                }
+            }
+        }
+        seite.schliesse_ab(&text[beginn..]);
+        seite.sprachen.retain(|sprache| !sprache.sprache.is_empty());
+        seite
+    }
+
+    /// The language sections of the given language, e.g. `Deutsch`.
+    pub fn sprache<'s>(
+        &'s self,
+        sprache: &'s str,
+    ) -> impl Iterator<Item = &'s Sprachabschnitt<'a>> + 's {
+        self.sprachen.iter().filter(move |s| s.sprache == sprache)
+    }
+
+    /// Every part-of-speech section of the given language that names the given part of speech.
+    pub fn wortarten<'s>(
+        &'s self,
+        sprache: &'s str,
+        wortart: &'s str,
+    ) -> impl Iterator<Item = &'s Wortartabschnitt<'a>> + 's {
+        self.sprache(sprache)
+            .flat_map(|s| &s.wortarten)
+            .filter(move |w| w.ist(wortart))
+    }
+
+    fn letzte_wortart(&mut self) -> Option<&mut Wortartabschnitt<'a>> {
+        self.sprachen.last_mut()?.wortarten.last_mut()
+    }
+
+    /// Hands the text since the last heading to the innermost open section.
+    fn schliesse_ab(&mut self, text: &'a str) {
+        let Some(wortart) = self.letzte_wortart() else {
+            return;
+        };
+        match wortart.unterabschnitte.last_mut() {
+            Some(unterabschnitt) if unterabschnitt.text.is_empty() => unterabschnitt.text = text,
+            Some(_) => {}
+            None if wortart.text.is_empty() => wortart.text = text,
+            None => {}
+        }
+    }
+}
+
+enum Zeile<'a> {
+    Sprache(Option<(&'a str, &'a str)>),
+    Wortart(&'a str),
+    Unterabschnitt(&'a str),
+}
+
+/// The level of a `== Heading ==` line, i.e. the number of leading `=`.
+fn ebene(zeile: &str) -> usize {
+    zeile.chars().take_while(|&c| c == '=').count()
+}
+
+fn ohne_gleichheitszeichen(zeile: &str) -> &str {
+    zeile.trim_matches(|c: char| c == '=' || c.is_whitespace())
+}
+
+/// `== Kopfnuss ({{Sprache|Deutsch}}) ==` gives `("Kopfnuss", "Deutsch")`.
+fn sprachueberschrift(zeile: &str) -> IResult<&str, (&str, &str)> {
+    let wort = map(take_until(" ({{Sprache|"), str::trim);
+    let sprache = delimited(tag(" ({{Sprache|"), take_till1(|c| c == '}'), tag("}})"));
+    let mut my_parser = all_consuming(tuple((wort, sprache)));
+    my_parser(ohne_gleichheitszeichen(zeile))
+}
+
+/// `==== {{Übersetzungen}} ====` gives `Übersetzungen`.
+fn unterabschnittsueberschrift(zeile: &str) -> IResult<&str, &str> {
+    textbaustein(ohne_gleichheitszeichen(zeile))
+}
+
+/// A line that consists of nothing but a template without parameters, e.g. `{{Bedeutungen}}`.
+fn textbaustein(zeile: &str) -> IResult<&str, &str> {
+    let name = take_till1(|c| c == '|' || c == '{' || c == '}');
+    let mut my_parser = all_consuming(terminated(
+        preceded(space0, delimited(tag("{{"), name, tag("}}"))),
+        space0,
+    ));
+    my_parser(zeile)
+}
+
+fn wortartueberschrift(zeile: &str) -> Wortartabschnitt<'_> {
+    let ueberschrift = ohne_gleichheitszeichen(zeile);
+    let mut wortarten = Vec::new();
+    let mut genera = Vec::new();
+    for vorlage in ueberschrift.split("{{").skip(1) {
+        let Some((inhalt, _)) = vorlage.split_once("}}") else {
+            continue;
+        };
+        let mut teile = inhalt.split('|');
+        match (teile.next(), teile.next()) {
+            (Some("Wortart"), Some(wortart)) => wortarten.push(wortart),
+            (Some(genus @ ("m" | "f" | "n")), None) => genera.push(genus),
+            _ => {}
+        }
+    }
+    Wortartabschnitt {
+        ueberschrift,
+        wortarten,
+        genera,
+        text: "",
+        unterabschnitte: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEITE: &str = "== Band ({{Sprache|Deutsch}}) ==
+=== {{Wortart|Substantiv|Deutsch}}, {{n}} ===
+
+{{Deutsch Substantiv Übersicht
+|Genus=n
+}}
+
+{{Bedeutungen}}
+:[1] [[Streifen]]
+
+==== {{Übersetzungen}} ====
+{{Ü-Tabelle|Ü-Liste=
+*{{en}}: [1] {{Ü|en|ribbon}}
+}}
+
+=== {{Wortart|Substantiv|Deutsch}}, {{f}} ===
+{{Deutsch Substantiv Übersicht
+|Genus=f
+}}
+
+== band ({{Sprache|Englisch}}) ==
+=== {{Wortart|Substantiv|Englisch}} ===
+{{Englisch Substantiv Übersicht
+}}
+";
+
+    #[test]
+    fn ueberschriften() {
+        assert_eq!(
+            sprachueberschrift("== Kopfnuss ({{Sprache|Deutsch}}) =="),
+            Ok(("", ("Kopfnuss", "Deutsch")))
+        );
+        assert_eq!(
+            sprachueberschrift("== Schwarzes Brett ({{Sprache|Deutsch}}) =="),
+            Ok(("", ("Schwarzes Brett", "Deutsch")))
+        );
+        assert!(sprachueberschrift("== Kopfnuss ==").is_err());
+        assert_eq!(textbaustein("{{Bedeutungen}}"), Ok(("", "Bedeutungen")));
+        assert_eq!(
+            unterabschnittsueberschrift("==== {{Übersetzungen}} ===="),
+            Ok(("", "Übersetzungen"))
+        );
+        assert!(textbaustein("{{Ähnlichkeiten 1|[[Kopfschuss]]}}").is_err());
+        assert!(textbaustein(":{{IPA}} {{Lautschrift|vɔʁt}}").is_err());
+
+        let wortart = wortartueberschrift(
+            "=== {{Wortart|Substantiv|Deutsch}}, {{m}}, {{Wortart|Toponym|Deutsch}} ===",
+        );
+        assert_eq!(
+            wortart.ueberschrift,
+            "{{Wortart|Substantiv|Deutsch}}, {{m}}, {{Wortart|Toponym|Deutsch}}"
+        );
+        assert_eq!(wortart.wortarten, ["Substantiv", "Toponym"]);
+        assert_eq!(wortart.genera, ["m"]);
+    }
+
+    #[test]
+    fn baum() {
+        let seite = Seite::new(SEITE);
+        assert_eq!(seite.sprachen.len(), 2);
+
+        let deutsch = &seite.sprachen[0];
+        assert_eq!((deutsch.wort, deutsch.sprache), ("Band", "Deutsch"));
+        assert_eq!(deutsch.wortarten.len(), 2);
+
+        let das_band = &deutsch.wortarten[0];
+        assert!(das_band.ist("Substantiv"));
+        assert_eq!(das_band.genera, ["n"]);
+        assert_eq!(
+            das_band.text,
+            "\n{{Deutsch Substantiv Übersicht\n|Genus=n\n}}\n\n"
+        );
+        assert_eq!(das_band.unterabschnitte.len(), 2);
+        assert_eq!(
+            das_band
+                .unterabschnitt(Abschnittsart::Bedeutungen)
+                .unwrap()
+                .text,
+            ":[1] [[Streifen]]\n\n"
+        );
+        assert!(das_band
+            .unterabschnitt(Abschnittsart::Uebersetzungen)
+            .unwrap()
+            .text
+            .contains("{{Ü|en|ribbon}}"));
+
+        let die_band = &deutsch.wortarten[1];
+        assert_eq!(die_band.genera, ["f"]);
+        assert!(die_band.unterabschnitte.is_empty());
+        assert!(die_band.text.contains("|Genus=f"));
+
+        let englisch = &seite.sprachen[1];
+        assert_eq!(englisch.sprache, "Englisch");
+        assert!(englisch.wortarten[0].text.contains("Englisch Substantiv"));
+    }
+
+    #[test]
+    fn nur_deutsche_wortarten() {
+        let seite = Seite::new(SEITE);
+        assert_eq!(seite.wortarten("Deutsch", "Substantiv").count(), 2);
+        assert_eq!(seite.wortarten("Englisch", "Substantiv").count(), 1);
+        assert_eq!(seite.wortarten("Deutsch", "Verb").count(), 0);
+    }
+
+    #[test]
+    fn ohne_sprachabschnitt() {
+        let seite = Seite::new("{{Deutsch Substantiv Übersicht\n|Genus=f\n}}\n{{Bedeutungen}}\n");
+        assert_eq!(seite, Seite::default());
+
+        let seite = Seite::new("== Hilfe ==\n=== {{Wortart|Substantiv|Deutsch}} ===\n");
+        assert_eq!(seite, Seite::default());
+    }
+}
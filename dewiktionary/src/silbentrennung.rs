@@ -0,0 +1,228 @@
+//! Splits words into syllables with hyphenation patterns in the style of Liang and TeX, for
+//! nouns without a `{{Worttrennung}}` block.
+//!
+//! A pattern like `s3chen` is a piece of a word with a number between two letters. Where
+//! several patterns meet, the highest number wins, and an odd number allows a break there.
+//! [`Silbentrenner::deutsch`] only knows a few rules; the full German patterns of TeX, e.g.
+//! `hyph-de-1996.pat.txt`, can be read with [`Silbentrenner::new`].
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+const VOKALE: &str = "aeiouyäöü";
+const KONSONANTEN: &str = "bcdfghjklmnpqrstvwxzß";
+
+/// Letters that are spoken as one consonant and stay together, like "sch" in "Fla·sche".
+const MEHRFACHKONSONANTEN: [&str; 4] = ["sch", "ch", "ck", "ph"];
+
+/// Patterns on top of the generated ones: breaks between the vowels of Latin and Greek
+/// words, like "Mu·se·um", "Fe·ri·en" and "The·a·ter".
+const AUSNAHMEN: [&str; 5] = ["e1um", "i1um", "ä1um", "i1en.", "e1a"];
+
+/// What comes before the "s" of a stem in diminutives like "Gläs·chen", "Häus·chen" and
+/// "Nüss·chen", so "-chen" is split off instead of "-schen" like in "Ta·schen". Such stems
+/// mostly get an umlaut, but not every word ending like this is a diminutive: "Wä·schen" and
+/// "Lö·schen" are split wrong.
+const VOR_VERKLEINERUNG: [&str; 5] = ["ä", "ö", "ü", "äu", "s"];
+
+#[derive(Debug, Default)]
+pub struct Silbentrenner {
+    /// The letters of each pattern and its numbers, one more than letters.
+    muster: HashMap<Vec<char>, Vec<u8>>,
+    laengstes: usize,
+}
+
+impl Silbentrenner {
+    /// Reads patterns like `1sch` or `c2h`. A `.` stands for the start or the end of a word.
+    /// The patterns of a `.pat.txt` file of TeX are read with `text.split_whitespace()`.
+    pub fn new<'a>(muster: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut trenner = Self::default();
+        for muster in muster {
+            trenner.lerne(muster);
+        }
+        trenner
+    }
+
+    /// The German patterns: a break before a consonant or one of `MEHRFACHKONSONANTEN`
+    /// followed by a vowel, so "Kin·der", "Fens·ter" and "Zu·cker", but none between vowels, so
+    /// "Mu·seum".
+    pub fn deutsch() -> &'static Self {
+        static DEUTSCH: OnceLock<Silbentrenner> = OnceLock::new();
+        DEUTSCH.get_or_init(|| {
+            let mut trenner = Self::default();
+            for vokal in VOKALE.chars() {
+                for konsonant in KONSONANTEN.chars() {
+                    trenner.lerne(&format!("1{konsonant}{vokal}"));
+                }
+                for mehrfach in MEHRFACHKONSONANTEN {
+                    trenner.lerne(&format!("1{mehrfach}{vokal}"));
+                }
+            }
+            for mehrfach in MEHRFACHKONSONANTEN {
+                let zeichen: Vec<String> = mehrfach.chars().map(String::from).collect();
+                trenner.lerne(&zeichen.join("2"));
+            }
+            for ausnahme in AUSNAHMEN {
+                trenner.lerne(ausnahme);
+            }
+            for davor in VOR_VERKLEINERUNG {
+                trenner.lerne(&format!("{davor}2s3chen."));
+            }
+            trenner
+        })
+    }
+
+    fn lerne(&mut self, muster: &str) {
+        let mut buchstaben = Vec::new();
+        let mut werte = vec![0];
+        for zeichen in muster.chars() {
+            match zeichen.to_digit(10) {
+                Some(wert) => *werte.last_mut().unwrap() = wert as u8,
+                None => {
+                    buchstaben.push(zeichen);
+                    werte.push(0);
+                }
+            }
+        }
+        self.laengstes = self.laengstes.max(buchstaben.len());
+        self.muster.insert(buchstaben, werte);
+    }
+
+    /// The syllables of a word, e.g. `["Kopf", "nüs", "se"]` for "Kopfnüsse". Like in a
+    /// `{{Worttrennung}}` block, a hyphen stays with the syllable before it.
+    pub fn trenne(&self, wort: &str) -> Vec<String> {
+        wort.split_inclusive('-')
+            .flat_map(|teil| self.trenne_teil(teil))
+            .collect()
+    }
+
+    fn trenne_teil(&self, teil: &str) -> Vec<String> {
+        let zeichen: Vec<char> = teil.chars().collect();
+        let klein: Vec<char> = ['.']
+            .into_iter()
+            .chain(teil.to_lowercase().chars())
+            .chain(['.'])
+            .collect();
+        if klein.len() != zeichen.len() + 2 {
+            // Lower case changed the length, e.g. for "İ"; such words are not split.
+            return vec![teil.to_string()];
+        }
+        // `werte[i]` is the number before `klein[i]`.
+        let mut werte = vec![0u8; klein.len() + 1];
+        for anfang in 0..klein.len() {
+            for ende in anfang + 1..=(anfang + self.laengstes).min(klein.len()) {
+                if let Some(muster) = self.muster.get(&klein[anfang..ende]) {
+                    for (i, wert) in muster.iter().enumerate() {
+                        werte[anfang + i] = werte[anfang + i].max(*wert);
+                    }
+                }
+            }
+        }
+        let mut silben = Vec::new();
+        let mut silbe = String::new();
+        for (i, buchstabe) in zeichen.iter().enumerate() {
+            // The break before `zeichen[i]` is the number before `klein[i + 1]`. Every syllable
+            // needs a vowel, so "Fla·sche" does not start with "F", and neither does a word
+            // after a space: "Schwar·ze Brett".
+            let wort = silbe.rsplit(' ').next().unwrap_or_default();
+            if hat_vokal(wort) && werte[i + 1] % 2 == 1 && zeichen[i] != '-' {
+                silben.push(std::mem::take(&mut silbe));
+            }
+            silbe.push(*buchstabe);
+        }
+        // The last syllable needs a vowel as well, so it is added to the one before.
+        match silben.last_mut() {
+            Some(letzte) if !hat_vokal(&silbe) => letzte.push_str(&silbe),
+            _ => silben.push(silbe),
+        }
+        silben
+    }
+}
+
+fn hat_vokal(wort: &str) -> bool {
+    wort.to_lowercase().chars().any(|c| VOKALE.contains(c))
+}
+
+/// How many words [`Silbentrenner::trenne`] splits exactly like their `{{Worttrennung}}`.
+#[derive(PartialEq, Eq, Debug, Default, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vergleich {
+    pub gesamt: usize,
+    pub richtig: usize,
+}
+
+/// Compares the patterns with known syllables, e.g. `["Kopf", "nuss"]`.
+pub fn vergleiche<'a>(
+    trenner: &Silbentrenner,
+    worttrennungen: impl IntoIterator<Item = &'a [String]>,
+) -> Vergleich {
+    let mut vergleich = Vergleich::default();
+    for silben in worttrennungen {
+        vergleich.gesamt += 1;
+        if trenner.trenne(&silben.concat()) == silben {
+            vergleich.richtig += 1;
+        }
+    }
+    vergleich
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn silben(wort: &str) -> String {
+        Silbentrenner::deutsch().trenne(wort).join("·")
+    }
+
+    #[test]
+    fn deutsch() {
+        assert_eq!(silben("Tage"), "Ta·ge");
+        assert_eq!(silben("Kinder"), "Kin·der");
+        assert_eq!(silben("Fenster"), "Fens·ter");
+        assert_eq!(silben("Flasche"), "Fla·sche");
+        assert_eq!(silben("Taschen"), "Ta·schen");
+        assert_eq!(silben("Duschen"), "Du·schen");
+        assert_eq!(silben("Menschen"), "Men·schen");
+        assert_eq!(silben("Häuschen"), "Häus·chen");
+        assert_eq!(silben("Gläschen"), "Gläs·chen");
+        assert_eq!(silben("Röschen"), "Rös·chen");
+        assert_eq!(silben("Höschen"), "Hös·chen");
+        assert_eq!(silben("Bläschen"), "Bläs·chen");
+        assert_eq!(silben("Nüsschen"), "Nüss·chen");
+        assert_eq!(silben("Zucker"), "Zu·cker");
+        assert_eq!(silben("Kuchen"), "Ku·chen");
+        assert_eq!(silben("Museum"), "Mu·se·um");
+        assert_eq!(silben("Ferien"), "Fe·ri·en");
+        assert_eq!(silben("Bienen"), "Bie·nen");
+        assert_eq!(silben("Zeitung"), "Zei·tung");
+        assert_eq!(silben("Abend"), "A·bend");
+        assert_eq!(silben("Hund"), "Hund");
+        assert_eq!(silben("E-Mail"), "E-·Mail");
+        assert_eq!(silben("Schwarze Brett"), "Schwar·ze Brett");
+    }
+
+    #[test]
+    fn muster() {
+        // "a2nd" beats "a1n" before "nd".
+        let trenner = Silbentrenner::new(["a1n", "a2nd"]);
+        assert_eq!(trenner.trenne("Anand"), ["A", "nand"]);
+        // "a1n" allows a break before the last "n", but "n" alone is no syllable.
+        assert_eq!(trenner.trenne("Anan"), ["A", "nan"]);
+    }
+
+    #[test]
+    fn vergleich() {
+        let worttrennungen = [vec!["Kopf", "nuss"], vec!["Erd", "ap", "fel"]]
+            .map(|silben| silben.into_iter().map(String::from).collect::<Vec<_>>());
+        assert_eq!(
+            vergleiche(
+                Silbentrenner::deutsch(),
+                worttrennungen.iter().map(Vec::as_slice)
+            ),
+            Vergleich {
+                gesamt: 2,
+                richtig: 1
+            }
+        );
+    }
+}
@@ -0,0 +1,169 @@
+//! Reads the translations of the `{{Ü-Tabelle}}` blocks of an `{{Übersetzungen}}` section.
+//!
+//! ```text
+//! {{Ü-Tabelle|Ü-Liste=
+//! *{{en}}: [1] {{Ü|en|lake}}
+//! *{{uk}}: [1] {{Üt|uk|озеро|ozero}} {{n}}
+//! }}
+//! ```
+//!
+//! A table may also belong to a single meaning, given as its first parameter, e.g.
+//! `{{Ü-Tabelle|2|G=eine [[Denksportaufgabe]]|Ü-Liste=…}}`.
+
+use crate::vorlage::{Knoten, Parameter, Vorlage};
+
+/// The parameters of `{{Ü-Tabelle}}` that hold lists of translations.
+const LISTEN: [&str; 3] = ["Ü-Liste", "Ü-links", "Ü-rechts"];
+
+/// One translation, e.g. `{{Üt|uk|озеро|ozero}}` gives the language `uk`, the text `озеро`
+/// and the transliteration `ozero`.
+#[derive(PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Uebersetzung {
+    pub sprache: String,
+    /// The meaning numbers the translation belongs to, e.g. `1` and `2` for `[1, 2]`.
+    pub bedeutungen: Vec<String>,
+    pub text: String,
+    pub umschrift: Option<String>,
+}
+
+impl Uebersetzung {
+    /// Reads the text of an `{{Übersetzungen}}` subsection. Empty translations such as
+    /// `{{Ü|en|}}` are skipped.
+    pub fn alle(text: &str) -> Vec<Self> {
+        let mut uebersetzungen = Vec::new();
+        for tabelle in Vorlage::alle(text) {
+            if tabelle.name != "Ü-Tabelle" {
+                continue;
+            }
+            let standard: Vec<String> = tabelle
+                .unbenannt(1)
+                .map(|nummer| nummer.text())
+                .filter(|nummer| !nummer.is_empty())
+                .into_iter()
+                .collect();
+            let listen = LISTEN.iter().filter_map(|name| tabelle.benannt(name));
+            for liste in listen {
+                let mut bedeutungen = standard.clone();
+                for knoten in &liste.wert {
+                    match knoten {
+                        Knoten::Text(text) => {
+                            for (i, zeile) in text.split('\n').enumerate() {
+                                if i > 0 {
+                                    bedeutungen = standard.clone();
+                                }
+                                if let Some(nummern) = letzte_nummern(zeile) {
+                                    bedeutungen = nummern;
+                                }
+                            }
+                        }
+                        Knoten::Vorlage(vorlage) => {
+                            uebersetzungen.extend(uebersetzung(vorlage, &bedeutungen));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+        uebersetzungen
+    }
+}
+
+/// `{{Ü|en|lake}}`, `{{Üt|uk|озеро|ozero}}` or `{{Üxx4|ar|kitab|v=كتاب|d=kitāb}}`.
+fn uebersetzung(vorlage: &Vorlage, bedeutungen: &[String]) -> Option<Uebersetzung> {
+    let wert =
+        |parameter: Option<&Parameter>| parameter.map(|p| p.text()).filter(|text| !text.is_empty());
+    let (text, umschrift) = match vorlage.name {
+        "Ü" => (wert(vorlage.unbenannt(2))?, None),
+        "Üt" => (wert(vorlage.unbenannt(2))?, wert(vorlage.unbenannt(3))),
+        name if name.starts_with("Üxx") => (
+            wert(vorlage.benannt("v")).or_else(|| wert(vorlage.unbenannt(2)))?,
+            wert(vorlage.benannt("d")),
+        ),
+        _ => return None,
+    };
+    Some(Uebersetzung {
+        sprache: wert(vorlage.unbenannt(1))?,
+        bedeutungen: bedeutungen.to_vec(),
+        text,
+        umschrift,
+    })
+}
+
+/// The numbers of the last `[1, 2]` of a line, if it starts with a digit.
+fn letzte_nummern(zeile: &str) -> Option<Vec<String>> {
+    let (_, rest) = zeile.rsplit_once('[')?;
+    let (nummern, _) = rest.split_once(']')?;
+    nummern.starts_with(|c: char| c.is_ascii_digit()).then(|| {
+        nummern
+            .split(',')
+            .map(|nummer| nummer.trim().to_string())
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn uebersetzung(sprache: &str, bedeutungen: &[&str], text: &str) -> Uebersetzung {
+        Uebersetzung {
+            sprache: sprache.to_string(),
+            bedeutungen: bedeutungen.iter().map(|b| b.to_string()).collect(),
+            text: text.to_string(),
+            umschrift: None,
+        }
+    }
+
+    #[test]
+    fn liste() {
+        let foo = Uebersetzung::alle(
+            "{{Ü-Tabelle|Ü-Liste=
+*{{en}}: [1] {{Ü|en|lake}}
+*{{fr}}: [1] {{Ü|fr|tape sur la tête}} {{f}}; [1, 2] {{Ü|fr|casse-tête}} {{m}}
+*{{uk}}: [1] {{Üt|uk|озеро|ozero}} {{n}}
+*{{ar}}: [1] {{Üxx4|ar|buhaira|v=بحيرة|d=buḥaira}}
+*{{sv}}: {{Ü|sv|}}
+}}",
+        );
+        assert_eq!(
+            foo,
+            [
+                uebersetzung("en", &["1"], "lake"),
+                uebersetzung("fr", &["1"], "tape sur la tête"),
+                uebersetzung("fr", &["1", "2"], "casse-tête"),
+                Uebersetzung {
+                    umschrift: Some("ozero".to_string()),
+                    ..uebersetzung("uk", &["1"], "озеро")
+                },
+                Uebersetzung {
+                    umschrift: Some("buḥaira".to_string()),
+                    ..uebersetzung("ar", &["1"], "بحيرة")
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn tabelle_je_bedeutung() {
+        let foo = Uebersetzung::alle(
+            "{{Ü-Tabelle|1|G=ein [[leicht]]er [[Schlag]]|Ü-Liste=
+*{{en}}: {{Ü|en|}}
+*{{sv}}: {{Ü|sv|smäll}}
+}}
+{{Ü-Tabelle|2|G=eine [[Denksportaufgabe]]|Ü-links=
+*{{en}}: {{Ü|en|brain teaser}}<ref>{{Wikipedia|Brain teaser|spr=en}}</ref>
+|Ü-rechts=
+*{{tr}}: {{Ü|tr|bilmece}}
+}}",
+        );
+        assert_eq!(
+            foo,
+            [
+                uebersetzung("sv", &["1"], "smäll"),
+                uebersetzung("en", &["2"], "brain teaser"),
+                uebersetzung("tr", &["2"], "bilmece"),
+            ]
+        );
+    }
+}
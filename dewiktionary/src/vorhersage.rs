@@ -0,0 +1,354 @@
+//! Guesses the gender of a noun from its ending, e.g. "-ung" for "die Zeitung", or from the
+//! last noun of a compound, e.g. "Tür" for "die Haustür". Every guess tells the rule it used,
+//! so a kid who picked the wrong article learns why.
+
+use crate::artikel::{artikel, Artikelart, Genus};
+use crate::parser::{Kasus, Numerus};
+use crate::zerlegung::zerlege;
+use std::collections::HashMap;
+
+/// A suffix rule like "-ung is feminine".
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Regel {
+    pub endung: &'static str,
+    pub genus: Genus,
+    /// How often the rule is right, between 0 and 1.
+    pub sicherheit: f32,
+}
+
+/// The suffix rules. The longest matching ending wins, so "-tum" beats "-um". There is no
+/// rule for "-ing": it is neuter in loanwords like "das Training", but not in "der Hering".
+pub const REGELN: [Regel; 29] = [
+    regel("ung", Genus::Femininum, 0.99),
+    regel("heit", Genus::Femininum, 0.99),
+    regel("keit", Genus::Femininum, 0.99),
+    regel("schaft", Genus::Femininum, 0.99),
+    regel("tät", Genus::Femininum, 0.99),
+    regel("ion", Genus::Femininum, 0.95),
+    regel("enz", Genus::Femininum, 0.95),
+    regel("anz", Genus::Femininum, 0.9),
+    regel("ei", Genus::Femininum, 0.9),
+    regel("ie", Genus::Femininum, 0.85),
+    regel("ik", Genus::Femininum, 0.85),
+    regel("ur", Genus::Femininum, 0.75),
+    regel("in", Genus::Femininum, 0.7),
+    regel("e", Genus::Femininum, 0.7),
+    regel("chen", Genus::Neutrum, 0.99),
+    regel("lein", Genus::Neutrum, 0.99),
+    regel("ment", Genus::Neutrum, 0.85),
+    regel("um", Genus::Neutrum, 0.75),
+    regel("tum", Genus::Neutrum, 0.8),
+    regel("nis", Genus::Neutrum, 0.7),
+    regel("ling", Genus::Maskulinum, 0.95),
+    regel("ismus", Genus::Maskulinum, 0.99),
+    regel("ist", Genus::Maskulinum, 0.9),
+    regel("eur", Genus::Maskulinum, 0.9),
+    regel("ig", Genus::Maskulinum, 0.85),
+    regel("ich", Genus::Maskulinum, 0.8),
+    regel("ant", Genus::Maskulinum, 0.8),
+    regel("or", Genus::Maskulinum, 0.75),
+    regel("ner", Genus::Maskulinum, 0.7),
+];
+
+const fn regel(endung: &'static str, genus: Genus, sicherheit: f32) -> Regel {
+    Regel {
+        endung,
+        genus,
+        sicherheit,
+    }
+}
+
+/// How sure a compound is to take the gender of its last noun. A few compounds do not, like
+/// "der Mittwoch" from "die Woche".
+const KOPF_SICHERHEIT: f32 = 0.95;
+
+/// A guessed gender with the rule that led to it.
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vorhersage {
+    pub genus: Genus,
+    /// Between 0 and 1.
+    pub sicherheit: f32,
+    /// The rule for the learner, e.g. `Wörter auf -ung haben immer den Artikel „die“.`
+    pub regel: String,
+}
+
+/// The gender of a known noun with the rule that explains it, for a kid who picked the wrong
+/// article.
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Erklaerung {
+    pub genus: Genus,
+    /// How sure the rule is, between 0 and 1.
+    pub sicherheit: f32,
+    /// The rule and the noun with its article, e.g. `Wörter auf -e haben meistens den Artikel
+    /// „die“. Aber „Käse“ ist eine Ausnahme: der Käse.`
+    pub regel: String,
+    /// Whether the rule gives another gender than the noun has.
+    pub ausnahme: bool,
+}
+
+/// The nouns a compound could end with, longest first and capitalized to look them up among
+/// the known nouns, e.g. "Haustür" gives "Stür" and "Tür". Both parts have at least three
+/// letters.
+pub fn koepfe(wort: &str) -> impl Iterator<Item = String> + '_ {
+    wort.char_indices()
+        .skip(3)
+        .map(|(i, _)| &wort[i..])
+        .filter(|kopf| kopf.chars().count() >= 3)
+        .map(|kopf| {
+            let mut zeichen = kopf.chars();
+            zeichen
+                .next()
+                .map(|erstes| erstes.to_uppercase().chain(zeichen).collect())
+                .unwrap_or_default()
+        })
+}
+
+/// Guesses the gender of a noun. `lexikon` holds the known nouns that may be parts of a
+/// compound; the noun itself is never looked up, so the guess also works for known nouns.
+/// Returns `None` if no rule fits.
+pub fn sage_voraus(wort: &str, lexikon: &HashMap<String, Genus>) -> Option<Vorhersage> {
+    let nach_endung = nach_endung(wort);
+    let nach_kopf = nach_zerlegung(wort, lexikon).or_else(|| {
+        koepfe(wort).find_map(|kopf| {
+            let genus = *lexikon.get(&kopf)?;
+            Some(Vorhersage {
+                genus,
+                sicherheit: KOPF_SICHERHEIT,
+                regel: format!(
+                    "„{wort}“ endet mit „{kopf}“. Zusammengesetzte Wörter haben den Artikel \
+                     des letzten Wortes: {} {kopf}.",
+                    bestimmt(genus),
+                ),
+            })
+        })
+    });
+    match (nach_endung, nach_kopf) {
+        (Some(endung), Some(kopf)) if kopf.sicherheit > endung.sicherheit => Some(kopf),
+        (Some(endung), _) => Some(endung),
+        (None, kopf) => kopf,
+    }
+}
+
+/// Explains the gender of a known noun with the rule of [`sage_voraus`]. `genera` are the
+/// genders of the noun, two for "der/die Sellerie", and the noun is an exception if the rule
+/// gives none of them. Returns `None` for a noun without a gender or if no rule fits.
+pub fn erklaere(
+    wort: &str,
+    genera: &[Genus],
+    lexikon: &HashMap<String, Genus>,
+) -> Option<Erklaerung> {
+    let erstes = *genera.first()?;
+    let vorhersage = sage_voraus(wort, lexikon)?;
+    let ausnahme = !genera.contains(&vorhersage.genus);
+    let genus = if ausnahme { erstes } else { vorhersage.genus };
+    let regel = if ausnahme {
+        format!(
+            "{} Aber „{wort}“ ist eine Ausnahme: {} {wort}.",
+            vorhersage.regel,
+            bestimmt(genus)
+        )
+    } else {
+        format!(
+            "{} Deshalb heißt es: {} {wort}.",
+            vorhersage.regel,
+            bestimmt(genus)
+        )
+    };
+    Some(Erklaerung {
+        genus,
+        sicherheit: vorhersage.sicherheit,
+        regel,
+        ausnahme,
+    })
+}
+
+/// How well [`sage_voraus`] does on a list of nouns.
+#[derive(PartialEq, Eq, Debug, Default, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Auswertung {
+    pub gesamt: usize,
+    /// The nouns a rule fits.
+    pub vorhergesagt: usize,
+    pub richtig: usize,
+}
+
+/// Guesses the gender of every noun with all the others as the lexicon and counts the right
+/// guesses.
+pub fn auswerten(woerter: &[(String, Genus)]) -> Auswertung {
+    let lexikon: HashMap<String, Genus> = woerter.iter().cloned().collect();
+    let mut auswertung = Auswertung::default();
+    for (wort, genus) in woerter {
+        auswertung.gesamt += 1;
+        if let Some(vorhersage) = sage_voraus(wort, &lexikon) {
+            auswertung.vorhergesagt += 1;
+            if vorhersage.genus == *genus {
+                auswertung.richtig += 1;
+            }
+        }
+    }
+    auswertung
+}
+
+/// The "Grundwort" rule for a compound that splits into known nouns, e.g. "Kopf + Schmerz +
+/// Tablette".
+fn nach_zerlegung(wort: &str, lexikon: &HashMap<String, Genus>) -> Option<Vorhersage> {
+    let zerlegung = zerlege(wort, lexikon).into_iter().next()?;
+    let teile: Vec<&str> = zerlegung
+        .zusammensetzung
+        .teile
+        .iter()
+        .map(|teil| teil.wort.as_str())
+        .collect();
+    let grundwort = zerlegung.zusammensetzung.kopf();
+    let artikel = bestimmt(zerlegung.genus);
+    Some(Vorhersage {
+        genus: zerlegung.genus,
+        sicherheit: KOPF_SICHERHEIT,
+        regel: format!(
+            "„{wort}“ ist {}. Das letzte Wort ist das Grundwort und bestimmt den Artikel: \
+             {artikel} {grundwort}.",
+            teile.join(" + "),
+        ),
+    })
+}
+
+fn nach_endung(wort: &str) -> Option<Vorhersage> {
+    let klein = wort.to_lowercase();
+    let regel = REGELN
+        .iter()
+        .filter(|r| klein.len() > r.endung.len() + 1 && klein.ends_with(r.endung))
+        .max_by_key(|r| r.endung.len())?;
+    let wie_oft = if regel.sicherheit >= 0.95 {
+        "immer"
+    } else {
+        "meistens"
+    };
+    Some(Vorhersage {
+        genus: regel.genus,
+        sicherheit: regel.sicherheit,
+        regel: format!(
+            "Wörter auf -{} haben {wie_oft} den Artikel „{}“.",
+            regel.endung,
+            bestimmt(regel.genus),
+        ),
+    })
+}
+
+fn bestimmt(genus: Genus) -> String {
+    artikel(
+        Artikelart::Bestimmt,
+        genus,
+        Kasus::Nominativ,
+        Numerus::Singular,
+    )
+    .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn endungen() {
+        let lexikon = HashMap::new();
+        let zeitung = sage_voraus("Zeitung", &lexikon).unwrap();
+        assert_eq!(zeitung.genus, Genus::Femininum);
+        assert_eq!(
+            zeitung.regel,
+            "Wörter auf -ung haben immer den Artikel „die“."
+        );
+        let maedchen = sage_voraus("Mädchen", &lexikon).unwrap();
+        assert_eq!(maedchen.genus, Genus::Neutrum);
+        assert_eq!(
+            sage_voraus("Frühling", &lexikon).map(|v| v.genus),
+            Some(Genus::Maskulinum)
+        );
+        assert_eq!(sage_voraus("Hund", &lexikon), None);
+        assert_eq!(sage_voraus("Hering", &lexikon), None);
+    }
+
+    #[test]
+    fn zusammensetzungen() {
+        let lexikon = HashMap::from([
+            ("Tür".to_string(), Genus::Femininum),
+            ("Haus".to_string(), Genus::Neutrum),
+            ("Hund".to_string(), Genus::Maskulinum),
+            ("Schlüssel".to_string(), Genus::Maskulinum),
+        ]);
+        // "Glas" is not known, so only the last word explains "Glastür".
+        let glastuer = sage_voraus("Glastür", &lexikon).unwrap();
+        assert_eq!(glastuer.genus, Genus::Femininum);
+        assert_eq!(
+            glastuer.regel,
+            "„Glastür“ endet mit „Tür“. Zusammengesetzte Wörter haben den Artikel des letzten \
+             Wortes: die Tür."
+        );
+        let schluessel = sage_voraus("Haustürschlüssel", &lexikon).unwrap();
+        assert_eq!(schluessel.genus, Genus::Maskulinum);
+        assert_eq!(
+            schluessel.regel,
+            "„Haustürschlüssel“ ist Haus + Tür + Schlüssel. Das letzte Wort ist das Grundwort und \
+             bestimmt den Artikel: der Schlüssel."
+        );
+        // The ending "-ung" is surer than the compound.
+        assert_eq!(
+            sage_voraus("Hundehaltung", &lexikon).map(|v| v.sicherheit),
+            Some(0.99)
+        );
+        assert_eq!(sage_voraus("Hund", &lexikon), None);
+        assert_eq!(
+            koepfe("Haustür").collect::<Vec<_>>(),
+            vec!["Stür".to_string(), "Tür".to_string()]
+        );
+    }
+
+    #[test]
+    fn ausnahmen() {
+        let lexikon = HashMap::new();
+        // "-e" is mostly feminine, but not for "Käse".
+        let kaese = erklaere("Käse", &[Genus::Maskulinum], &lexikon).unwrap();
+        assert_eq!(kaese.genus, Genus::Maskulinum);
+        assert!(kaese.ausnahme);
+        assert_eq!(
+            kaese.regel,
+            "Wörter auf -e haben meistens den Artikel „die“. Aber „Käse“ ist eine Ausnahme: \
+             der Käse."
+        );
+        let zeitung = erklaere("Zeitung", &[Genus::Femininum], &lexikon).unwrap();
+        assert!(!zeitung.ausnahme);
+        assert_eq!(
+            zeitung.regel,
+            "Wörter auf -ung haben immer den Artikel „die“. Deshalb heißt es: die Zeitung."
+        );
+        // "die Sellerie" fits the rule for "-ie", so "der Sellerie" is no exception either.
+        let genera = [Genus::Maskulinum, Genus::Femininum];
+        let sellerie = erklaere("Sellerie", &genera, &lexikon).unwrap();
+        assert_eq!(sellerie.genus, Genus::Femininum);
+        assert!(!sellerie.ausnahme);
+        assert_eq!(erklaere("Käse", &[], &lexikon), None);
+        assert_eq!(erklaere("Hund", &[Genus::Maskulinum], &lexikon), None);
+    }
+
+    #[test]
+    fn auswertung() {
+        let woerter = [
+            ("Zeitung", Genus::Femininum),
+            ("Tür", Genus::Femininum),
+            ("Haustür", Genus::Femininum),
+            ("Hund", Genus::Maskulinum),
+            ("Sahne", Genus::Femininum),
+            ("Käse", Genus::Maskulinum),
+        ]
+        .map(|(wort, genus)| (wort.to_string(), genus));
+        assert_eq!(
+            auswerten(&woerter),
+            Auswertung {
+                gesamt: 6,
+                vorhergesagt: 4,
+                richtig: 3
+            }
+        );
+    }
+}
@@ -0,0 +1,312 @@
+//! Parses wikitext with templates, e.g. `{{Ü|en|[[lake]]}}`, into a tree of nodes.
+//!
+//! Parameter values keep their markup as nodes, so a `|` inside a nested template, a
+//! `[[Ziel|Text]]` link or a `<ref>` does not end the parameter it belongs to.
+
+use nom::branch::alt;
+use nom::bytes::complete::{tag, take_till, take_till1, take_until};
+use nom::combinator::{map, opt, verify};
+use nom::error::{Error, ErrorKind};
+use nom::multi::many0;
+use nom::sequence::{delimited, pair, preceded, terminated};
+use nom::IResult;
+
+/// The openings of markup that becomes a node of its own.
+const OEFFNER: [&str; 4] = ["{{", "[[", "<ref", "<!--"];
+
+/// Ends the value of a template parameter.
+const PARAMETER_ENDE: &[&str] = &["|", "}}"];
+
+/// Ends the content of a `<ref>`.
+const REFERENZ_ENDE: &[&str] = &["</ref>"];
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum Knoten<'a> {
+    Text(&'a str),
+    Vorlage(Vorlage<'a>),
+    Verweis(Verweis<'a>),
+    /// The content of `<ref>…</ref>`. It is empty for `<ref name="x" />`.
+    Referenz(Vec<Knoten<'a>>),
+    /// The content of `<!-- … -->`.
+    Kommentar(&'a str),
+}
+
+impl<'a> Knoten<'a> {
+    /// Parses a whole text. Markup that is not closed is kept as text.
+    pub fn alle(text: &'a str) -> Vec<Self> {
+        let mut my_parser = many0(knoten(&[]));
+        my_parser(text)
+            .map(|(_rest, knoten)| knoten)
+            .unwrap_or_default()
+    }
+}
+
+/// A link such as `[[Kopf]]` or `[[Kopfnuss|Kopfnüsse]]`.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Verweis<'a> {
+    pub ziel: &'a str,
+    pub anzeige: Option<&'a str>,
+}
+
+impl<'a> Verweis<'a> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        let ziel = take_till1(|c| matches!(c, '|' | '[' | ']' | '{' | '}'));
+        let anzeige = opt(preceded(tag("|"), take_until("]]")));
+        let mut my_parser = map(
+            delimited(tag("[["), pair(ziel, anzeige), tag("]]")),
+            |(ziel, anzeige)| Self { ziel, anzeige },
+        );
+        my_parser(input)
+    }
+
+    /// The text a reader sees.
+    pub fn text(&self) -> &'a str {
+        self.anzeige.unwrap_or(self.ziel)
+    }
+}
+
+/// A `|positional` or `|key=value` parameter of a template.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Parameter<'a> {
+    pub name: Option<&'a str>,
+    pub wert: Vec<Knoten<'a>>,
+}
+
+impl<'a> Parameter<'a> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        let name = terminated(
+            map(
+                take_till1(|c| matches!(c, '=' | '|' | '{' | '}' | '[' | ']' | '<')),
+                str::trim,
+            ),
+            tag("="),
+        );
+        let wert = many0(knoten(PARAMETER_ENDE));
+        let mut my_parser = map(pair(opt(name), wert), |(name, wert)| Self { name, wert });
+        my_parser(input)
+    }
+
+    /// The value as plain text without surrounding whitespace, see [`klartext`].
+    pub fn text(&self) -> String {
+        klartext(&self.wert).trim().to_string()
+    }
+}
+
+/// A template such as `{{Deutsch Substantiv Übersicht|Genus=f|…}}`.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Vorlage<'a> {
+    pub name: &'a str,
+    pub parameter: Vec<Parameter<'a>>,
+}
+
+impl<'a> Vorlage<'a> {
+    pub(crate) fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        let name = map(take_till1(|c| matches!(c, '|' | '{' | '}')), str::trim);
+        let parameter = many0(preceded(tag("|"), Parameter::parse));
+        let mut my_parser = map(
+            delimited(tag("{{"), pair(name, parameter), tag("}}")),
+            |(name, parameter)| Self { name, parameter },
+        );
+        my_parser(input)
+    }
+
+    /// The templates of a text that are not nested in other markup, in the order of the text.
+    pub fn alle(text: &'a str) -> Vec<Self> {
+        Knoten::alle(text)
+            .into_iter()
+            .filter_map(|knoten| match knoten {
+                Knoten::Vorlage(vorlage) => Some(vorlage),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The first template of a text with the given name.
+    pub fn finde(text: &'a str, name: &str) -> Option<Self> {
+        Self::alle(text)
+            .into_iter()
+            .find(|vorlage| vorlage.name == name)
+    }
+
+    /// The first parameter with the given name.
+    pub fn benannt(&self, name: &str) -> Option<&Parameter<'a>> {
+        self.parameter.iter().find(|p| p.name == Some(name))
+    }
+
+    /// The positional parameter with the given number, counting from 1 like MediaWiki does.
+    pub fn unbenannt(&self, nummer: usize) -> Option<&Parameter<'a>> {
+        self.parameter
+            .iter()
+            .filter(|p| p.name.is_none())
+            .nth(nummer.checked_sub(1)?)
+    }
+}
+
+/// The text a reader sees: links are replaced by their text, while templates, references and
+/// comments are dropped.
+pub fn klartext(knoten: &[Knoten]) -> String {
+    let mut text = String::new();
+    for k in knoten {
+        match k {
+            Knoten::Text(t) => text.push_str(t),
+            Knoten::Verweis(verweis) => text.push_str(verweis.text()),
+            Knoten::Vorlage(_) | Knoten::Referenz(_) | Knoten::Kommentar(_) => {}
+        }
+    }
+    text
+}
+
+/// A line of wikitext as a reader sees it: the [`klartext`] without bold and italic quotes and
+/// with runs of whitespace collapsed to a single space.
+pub fn fliesstext(zeile: &str) -> String {
+    klartext(&Knoten::alle(zeile))
+        .replace("'''", "")
+        .replace("''", "")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn knoten<'a>(
+    ende: &'static [&'static str],
+) -> impl FnMut(&'a str) -> IResult<&'a str, Knoten<'a>> {
+    move |input| {
+        let mut my_parser = alt((
+            map(Vorlage::parse, Knoten::Vorlage),
+            map(Verweis::parse, Knoten::Verweis),
+            map(referenz, Knoten::Referenz),
+            map(kommentar, Knoten::Kommentar),
+            map(text(ende), Knoten::Text),
+        ));
+        my_parser(input)
+    }
+}
+
+/// Text up to the next markup or the given end. The first character is always taken, so an
+/// opening `{{` that is never closed becomes text.
+fn text<'a>(ende: &'static [&'static str]) -> impl FnMut(&'a str) -> IResult<&'a str, &'a str> {
+    move |input| {
+        let endet = |rest: &str| ende.iter().any(|e| rest.starts_with(e));
+        if input.is_empty() || endet(input) {
+            return Err(nom::Err::Error(Error::new(input, ErrorKind::TakeTill1)));
+        }
+        let laenge = input
+            .char_indices()
+            .skip(1)
+            .map(|(i, _)| i)
+            .find(|&i| {
+                let rest = &input[i..];
+                endet(rest) || OEFFNER.iter().any(|o| rest.starts_with(o))
+            })
+            .unwrap_or(input.len());
+        Ok((&input[laenge..], &input[..laenge]))
+    }
+}
+
+/// `<ref>…</ref>`, `<ref name="x">…</ref>` or `<ref name="x" />`, but not `<references />`.
+fn referenz(input: &str) -> IResult<&str, Vec<Knoten<'_>>> {
+    let attribute = verify(take_till(|c| c == '>'), |a: &str| {
+        a.is_empty() || a.starts_with(char::is_whitespace)
+    });
+    let (input, attribute) = delimited(tag("<ref"), attribute, tag(">"))(input)?;
+    if attribute.ends_with('/') {
+        return Ok((input, Vec::new()));
+    }
+    let mut my_parser = terminated(many0(knoten(REFERENZ_ENDE)), tag("</ref>"));
+    my_parser(input)
+}
+
+fn kommentar(input: &str) -> IResult<&str, &str> {
+    let mut my_parser = delimited(tag("<!--"), take_until("-->"), tag("-->"));
+    my_parser(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vorlage() {
+        let vorlage = Vorlage::finde("*{{en}}: [1] {{Ü|en|lake}}", "Ü").unwrap();
+        assert_eq!(vorlage.name, "Ü");
+        assert_eq!(vorlage.unbenannt(1).unwrap().text(), "en");
+        assert_eq!(vorlage.unbenannt(2).unwrap().text(), "lake");
+        assert_eq!(vorlage.unbenannt(3), None);
+        assert_eq!(vorlage.unbenannt(0), None);
+
+        let vorlage = Vorlage::finde(
+            "{{Deutsch Substantiv Übersicht\n|Genus=f\n|Bild=\n}}",
+            "Deutsch Substantiv Übersicht",
+        )
+        .unwrap();
+        assert_eq!(vorlage.benannt("Genus").unwrap().text(), "f");
+        assert_eq!(
+            vorlage.benannt("Bild").unwrap().wert,
+            vec![Knoten::Text("\n")]
+        );
+        assert_eq!(vorlage.benannt("Nominativ Singular"), None);
+    }
+
+    #[test]
+    fn verschachtelt() {
+        let vorlage = Vorlage::finde(
+            "{{Lit-Duden: Rechtschreibung|A=26|Seite=123}}{{Ü-Tabelle|Ü-Links={{Ü|en|lake}}, {{Ü|tr|göl}}|Ü-Rechts=}}",
+            "Ü-Tabelle",
+        )
+        .unwrap();
+        let links = vorlage.benannt("Ü-Links").unwrap();
+        let uebersetzungen: Vec<&str> = links
+            .wert
+            .iter()
+            .filter_map(|k| match k {
+                Knoten::Vorlage(v) => v.unbenannt(2).map(|p| p.wert.as_slice()),
+                _ => None,
+            })
+            .map(|wert| match wert {
+                [Knoten::Text(t)] => *t,
+                _ => "",
+            })
+            .collect();
+        assert_eq!(uebersetzungen, ["lake", "göl"]);
+        assert_eq!(vorlage.parameter.len(), 2);
+    }
+
+    #[test]
+    fn verweise_referenzen_und_kommentare() {
+        let vorlage = Vorlage::finde(
+            "{{Beispiel|[[Kopfnuss|Kopfnüsse]] tun weh.<ref>{{Literatur|Titel=A|Seite=1}}</ref><!-- | -->|zwei<ref name=\"x\" />}}",
+            "Beispiel",
+        )
+        .unwrap();
+        assert_eq!(vorlage.parameter.len(), 2);
+        assert_eq!(vorlage.unbenannt(1).unwrap().text(), "Kopfnüsse tun weh.");
+        assert_eq!(
+            vorlage.unbenannt(2).unwrap().wert,
+            vec![Knoten::Text("zwei"), Knoten::Referenz(Vec::new())]
+        );
+        assert_eq!(
+            Knoten::alle("<references />"),
+            vec![Knoten::Text("<references />")]
+        );
+    }
+
+    #[test]
+    fn nicht_geschlossen() {
+        let knoten = Knoten::alle("a {{Deutsch Substantiv Übersicht\n|Genus=m\n[[Baum");
+        assert!(knoten.iter().all(|k| matches!(k, Knoten::Text(_))));
+        assert_eq!(
+            klartext(&knoten),
+            "a {{Deutsch Substantiv Übersicht\n|Genus=m\n[[Baum"
+        );
+        assert_eq!(Vorlage::alle("}} {{}} ]]"), vec![]);
+    }
+
+    #[test]
+    fn fliesstext_ohne_markup() {
+        assert_eq!(
+            fliesstext("{{K|Anatomie}} [[Knochen]], der  die [[Zahn|Zähne]] ''trägt''<ref>X</ref>"),
+            "Knochen, der die Zähne trägt"
+        );
+        assert_eq!(fliesstext("'''Band''' im {{Pl.}}\tHaar"), "Band im Haar");
+    }
+}
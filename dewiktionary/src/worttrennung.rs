@@ -0,0 +1,87 @@
+//! Reads the syllables of a `{{Worttrennung}}` block, e.g. `:Kopf·nuss, {{Pl.}} Kopf·nüs·se`.
+
+use crate::vorlage::Knoten;
+
+/// The syllables of the singular and plural forms of a `{{Worttrennung}}` block, in the order
+/// of the block. `{{Pl.1}}` and `{{Pl.2}}` give two plurals, while `{{kPl.}}` or `—` give none.
+#[derive(PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Worttrennung {
+    pub singular: Vec<Vec<String>>,
+    pub plural: Vec<Vec<String>>,
+}
+
+impl Worttrennung {
+    /// Reads the text of a `{{Worttrennung}}` subsection.
+    pub fn new(text: &str) -> Self {
+        let mut worttrennung = Self::default();
+        for zeile in text.lines().filter_map(|zeile| zeile.strip_prefix(':')) {
+            let mut plural = false;
+            for knoten in Knoten::alle(zeile) {
+                match knoten {
+                    Knoten::Vorlage(vorlage) if vorlage.name.starts_with("Pl.") => plural = true,
+                    Knoten::Vorlage(vorlage) if vorlage.name.starts_with("Sg.") => plural = false,
+                    Knoten::Text(text) => {
+                        let formen = if plural {
+                            &mut worttrennung.plural
+                        } else {
+                            &mut worttrennung.singular
+                        };
+                        formen.extend(text.split([',', ';']).filter_map(silben));
+                    }
+                    _ => {}
+                }
+            }
+        }
+        worttrennung
+    }
+}
+
+/// `Kopf·nüs·se` gives `["Kopf", "nüs", "se"]`. A hyphen is a break, too, and stays with the
+/// syllable before it, so `E-Mail` gives `["E-", "Mail"]`.
+fn silben(form: &str) -> Option<Vec<String>> {
+    let form = form.trim();
+    if form.is_empty() || form == "—" || form == "…" {
+        return None;
+    }
+    Some(
+        form.split('·')
+            .flat_map(|silbe| silbe.split_inclusive('-'))
+            .map(|silbe| silbe.trim().to_string())
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn singular_und_plural() {
+        let foo = Worttrennung::new(":Kopf·nuss, {{Pl.}} Kopf·nüs·se\n\n");
+        assert_eq!(foo.singular, [["Kopf", "nuss"]]);
+        assert_eq!(foo.plural, [["Kopf", "nüs", "se"]]);
+
+        let foo = Worttrennung::new(":E-Mail, {{Pl.}} E-Mails");
+        assert_eq!(foo.singular, [["E-", "Mail"]]);
+    }
+
+    #[test]
+    fn mehrere_plurale() {
+        let foo = Worttrennung::new(":Jo·ghurt, {{Pl.1}} Jo·ghurts, {{Pl.2}} Jo·ghurt");
+        assert_eq!(foo.singular, [vec!["Jo", "ghurt"]]);
+        assert_eq!(foo.plural, [["Jo", "ghurts"], ["Jo", "ghurt"]]);
+    }
+
+    #[test]
+    fn ohne_plural() {
+        let foo = Worttrennung::new(":Durst, {{Pl.}} —");
+        assert_eq!(foo.singular, [["Durst"]]);
+        assert!(foo.plural.is_empty());
+
+        let foo = Worttrennung::new(":{{kPl.}}\n:Ge·schwis·ter, {{Pl.}} Ge·schwis·ter");
+        assert_eq!(foo.singular, [["Ge", "schwis", "ter"]]);
+        assert_eq!(foo.plural, [["Ge", "schwis", "ter"]]);
+        assert_eq!(Worttrennung::new(""), Worttrennung::default());
+    }
+}
@@ -0,0 +1,247 @@
+//! Splits a compound into known nouns, e.g. "Kopfschmerztablette" into "Kopf", "Schmerz" and
+//! "Tablette", for the many compounds without a `{{Herkunft}}` block. The compound takes the
+//! gender of its last part, the "Grundwort".
+
+use crate::artikel::Genus;
+use crate::herkunft::{Bestandteil, Zusammensetzung};
+use std::collections::HashMap;
+
+/// The linking elements that may follow a part, e.g. "s" in "Arbeitszimmer".
+pub const FUGEN: [&str; 6] = ["s", "es", "n", "en", "e", "er"];
+
+/// Parts are at least this many letters long, so "Haustür" is not "Hau", "S" and "Tür".
+const MINDESTLAENGE: usize = 3;
+
+/// How much a linking element lowers the score of a split.
+const FUGE_ABZUG: f32 = 0.05;
+
+/// How many splits [`zerlege`] returns at most. Long words have thousands of splits, of which
+/// only the best few are of any use.
+const HOECHSTENS: usize = 5;
+
+/// A possible split of a compound.
+#[derive(PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Zerlegung {
+    pub zusammensetzung: Zusammensetzung,
+    /// The gender of the last part, which is the gender of the compound.
+    pub genus: Genus,
+    /// Between 0 and 1. Fewer and longer parts and fewer linking elements score higher.
+    pub punkte: f32,
+}
+
+/// The best splits of a word into at least two nouns of `lexikon`, the best first and at most
+/// [`HOECHSTENS`]. The word itself is never looked up, so known compounds are split as well.
+pub fn zerlege(wort: &str, lexikon: &HashMap<String, Genus>) -> Vec<Zerlegung> {
+    let laenge = wort.chars().count() as f32;
+    teile(wort, 0, laenge, lexikon, &mut HashMap::new())
+        .into_iter()
+        .filter(|(_, teile)| teile.len() >= 2)
+        .take(HOECHSTENS)
+        .filter_map(|(punkte, teile)| {
+            let genus = *lexikon.get(&teile.last()?.wort)?;
+            Some(Zerlegung {
+                punkte: punkte.max(0.0),
+                zusammensetzung: Zusammensetzung { teile },
+                genus,
+            })
+        })
+        .collect()
+}
+
+/// Every piece of a word that could be a part, capitalized, e.g. to load just these nouns
+/// from the database before calling [`zerlege`].
+pub fn kandidaten(wort: &str) -> Vec<String> {
+    let grenzen: Vec<usize> = wort
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain([wort.len()])
+        .collect();
+    let mut kandidaten = Vec::new();
+    for (a, &anfang) in grenzen.iter().enumerate() {
+        for &ende in grenzen.iter().skip(a + MINDESTLAENGE) {
+            let kandidat = gross(&wort[anfang..ende]);
+            let ganz = anfang == 0 && ende == wort.len();
+            if !ganz && !kandidaten.contains(&kandidat) {
+                kandidaten.push(kandidat);
+            }
+        }
+    }
+    kandidaten
+}
+
+/// A split with its score.
+type Teilung = (f32, Vec<Bestandteil>);
+
+/// The best splits of `wort[anfang..]` into nouns of `lexikon`, including the one with a single
+/// part. Every part adds the square of its share of the `laenge` of the whole word and every
+/// linking element takes off [`FUGE_ABZUG`], so the best splits of a word start with a part
+/// followed by one of the best splits of the rest. `beste` keeps them for every start, and one
+/// more than [`HOECHSTENS`] in case the single part is among them.
+fn teile(
+    wort: &str,
+    anfang: usize,
+    laenge: f32,
+    lexikon: &HashMap<String, Genus>,
+    beste: &mut HashMap<usize, Vec<Teilung>>,
+) -> Vec<Teilung> {
+    if let Some(teilungen) = beste.get(&anfang) {
+        return teilungen.clone();
+    }
+    let rest = &wort[anfang..];
+    let mut ergebnis = Vec::new();
+    let enden = rest
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain([rest.len()])
+        .skip(MINDESTLAENGE);
+    for ende in enden {
+        let teil = gross(&rest[..ende]);
+        if !lexikon.contains_key(&teil) {
+            continue;
+        }
+        let punkte = (teil.chars().count() as f32 / laenge).powi(2);
+        if ende == rest.len() {
+            ergebnis.push((
+                punkte,
+                vec![Bestandteil {
+                    wort: teil,
+                    fuge: None,
+                }],
+            ));
+            continue;
+        }
+        for fuge in [""].into_iter().chain(FUGEN) {
+            if !rest[ende..].starts_with(fuge) {
+                continue;
+            }
+            let abzug = if fuge.is_empty() { 0.0 } else { FUGE_ABZUG };
+            let danach = anfang + ende + fuge.len();
+            for (weitere_punkte, mut weitere) in teile(wort, danach, laenge, lexikon, beste) {
+                weitere.insert(
+                    0,
+                    Bestandteil {
+                        wort: teil.clone(),
+                        fuge: Some(fuge.to_string()).filter(|f| !f.is_empty()),
+                    },
+                );
+                ergebnis.push((punkte - abzug + weitere_punkte, weitere));
+            }
+        }
+    }
+    ergebnis.sort_by(|a, b| b.0.total_cmp(&a.0));
+    ergebnis.truncate(HOECHSTENS + 1);
+    beste.insert(anfang, ergebnis.clone());
+    ergebnis
+}
+
+fn gross(wort: &str) -> String {
+    let mut zeichen = wort.chars();
+    zeichen
+        .next()
+        .map(|erstes| erstes.to_uppercase().chain(zeichen).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lexikon(woerter: &[(&str, Genus)]) -> HashMap<String, Genus> {
+        woerter
+            .iter()
+            .map(|(wort, genus)| (wort.to_string(), *genus))
+            .collect()
+    }
+
+    fn teile(zerlegung: &Zerlegung) -> Vec<(&str, Option<&str>)> {
+        zerlegung
+            .zusammensetzung
+            .teile
+            .iter()
+            .map(|teil| (teil.wort.as_str(), teil.fuge.as_deref()))
+            .collect()
+    }
+
+    #[test]
+    fn zerlegen() {
+        let lexikon = lexikon(&[
+            ("Kopf", Genus::Maskulinum),
+            ("Schmerz", Genus::Maskulinum),
+            ("Tablette", Genus::Femininum),
+            ("Arbeit", Genus::Femininum),
+            ("Zimmer", Genus::Neutrum),
+        ]);
+        let zerlegungen = zerlege("Kopfschmerztablette", &lexikon);
+        assert_eq!(zerlegungen.len(), 1);
+        assert_eq!(
+            teile(&zerlegungen[0]),
+            vec![("Kopf", None), ("Schmerz", None), ("Tablette", None)]
+        );
+        assert_eq!(zerlegungen[0].genus, Genus::Femininum);
+        let zerlegungen = zerlege("Arbeitszimmer", &lexikon);
+        assert_eq!(
+            teile(&zerlegungen[0]),
+            vec![("Arbeit", Some("s")), ("Zimmer", None)]
+        );
+        assert_eq!(zerlegungen[0].genus, Genus::Neutrum);
+        assert!(zerlege("Kopf", &lexikon).is_empty());
+        assert!(zerlege("Kopfsalat", &lexikon).is_empty());
+    }
+
+    #[test]
+    fn punkte() {
+        let lexikon = lexikon(&[
+            ("Stau", Genus::Maskulinum),
+            ("Staub", Genus::Maskulinum),
+            ("Becken", Genus::Neutrum),
+            ("Ecken", Genus::Femininum),
+            ("Kopf", Genus::Maskulinum),
+            ("Schmerz", Genus::Maskulinum),
+            ("Kopfschmerz", Genus::Maskulinum),
+            ("Tablette", Genus::Femininum),
+        ]);
+        // Both splits are words, the longer "Becken" wins.
+        let zerlegungen = zerlege("Staubecken", &lexikon);
+        assert_eq!(
+            zerlegungen.iter().map(teile).collect::<Vec<_>>(),
+            vec![
+                vec![("Stau", None), ("Becken", None)],
+                vec![("Staub", None), ("Ecken", None)],
+            ]
+        );
+        let zerlegungen = zerlege("Kopfschmerztablette", &lexikon);
+        assert_eq!(zerlegungen.len(), 2);
+        assert_eq!(
+            teile(&zerlegungen[0]),
+            vec![("Kopfschmerz", None), ("Tablette", None)]
+        );
+    }
+
+    #[test]
+    fn lange_woerter() {
+        let lexikon = lexikon(&[
+            ("Haus", Genus::Neutrum),
+            ("Hau", Genus::Maskulinum),
+            ("Aus", Genus::Neutrum),
+            ("Shaus", Genus::Neutrum),
+        ]);
+        // Tens of thousands of splits, but every rest is only split once.
+        let zerlegungen = zerlege(&"Haus".repeat(10), &lexikon);
+        assert_eq!(zerlegungen.len(), HOECHSTENS);
+        assert_eq!(teile(&zerlegungen[0]), vec![("Haus", None); 10]);
+        assert!(zerlegungen
+            .windows(2)
+            .all(|paar| paar[0].punkte >= paar[1].punkte));
+    }
+
+    #[test]
+    fn kandidaten_laden() {
+        let kandidaten = kandidaten("Haustür");
+        assert!(kandidaten.contains(&"Haus".to_string()));
+        assert!(kandidaten.contains(&"Tür".to_string()));
+        assert!(!kandidaten.contains(&"Haustür".to_string()));
+        // 5 pieces start at "H", 4 at "a", …, 1 at "t", without the whole word.
+        assert_eq!(kandidaten.len(), 14);
+    }
+}
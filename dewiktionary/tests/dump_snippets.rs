@@ -0,0 +1,341 @@
+//! Regression suite built from snippets of the German Wiktionary dump.
+//!
+//! Every file in `tests/snippets` is the wikitext of a real page. These pages used to be
+//! dropped by the importer, so each test pins down what the parser has to return for them.
+
+use dewiktionary::beziehung::Beziehungsart;
+use dewiktionary::parser::{
+    Adjektivabschnitt, DeutschSubstantivUebersicht, Kasus, Numerus, Substantivabschnitt, Tantum,
+    Verbabschnitt,
+};
+
+fn uebersicht(seite: &str) -> DeutschSubstantivUebersicht {
+    DeutschSubstantivUebersicht::new(seite).expect("Substantivtabelle nicht gefunden")
+}
+
+#[test]
+fn kopfnuss() {
+    let t = uebersicht(include_str!("snippets/kopfnuss.wiki"));
+    assert_eq!(t.genus[0], "f");
+    assert_eq!(t.form(Kasus::Nominativ, Numerus::Singular)[0], "Kopfnuss");
+    assert_eq!(t.form(Kasus::Nominativ, Numerus::Plural)[0], "Kopfnüsse");
+    assert_eq!(t.form(Kasus::Genitiv, Numerus::Plural)[0], "Kopfnüsse");
+    assert_eq!(t.form(Kasus::Dativ, Numerus::Plural)[0], "Kopfnüssen");
+    assert_eq!(t.form(Kasus::Akkusativ, Numerus::Plural)[0], "Kopfnüsse");
+}
+
+#[test]
+fn strasse() {
+    let t = uebersicht(include_str!("snippets/strasse.wiki"));
+    assert_eq!(t.genus[0], "f");
+    assert_eq!(t.form(Kasus::Nominativ, Numerus::Singular)[0], "Straße");
+    assert_eq!(t.form(Kasus::Nominativ, Numerus::Plural)[0], "Straßen");
+    assert_eq!(t.form(Kasus::Dativ, Numerus::Plural)[0], "Straßen");
+}
+
+#[test]
+fn apfel() {
+    let t = uebersicht(include_str!("snippets/apfel.wiki"));
+    assert_eq!(t.genus[0], "m");
+    assert_eq!(t.form(Kasus::Nominativ, Numerus::Singular)[0], "Apfel");
+    assert_eq!(t.form(Kasus::Nominativ, Numerus::Plural)[0], "Äpfel");
+    assert_eq!(t.form(Kasus::Genitiv, Numerus::Singular)[0], "Apfels");
+    assert_eq!(t.form(Kasus::Dativ, Numerus::Plural)[0], "Äpfeln");
+}
+
+#[test]
+fn e_mail() {
+    let t = uebersicht(include_str!("snippets/e-mail.wiki"));
+    assert_eq!(t.genus[0], "f");
+    assert_eq!(t.form(Kasus::Nominativ, Numerus::Singular)[0], "E-Mail");
+    assert_eq!(t.form(Kasus::Nominativ, Numerus::Plural)[0], "E-Mails");
+}
+
+#[test]
+fn schwarzes_brett() {
+    let t = uebersicht(include_str!("snippets/schwarzes-brett.wiki"));
+    assert_eq!(t.genus[0], "n");
+    assert_eq!(
+        t.form(Kasus::Nominativ, Numerus::Singular)[0],
+        "Schwarze Brett"
+    );
+    assert_eq!(
+        t.form(Kasus::Genitiv, Numerus::Singular)[0],
+        "Schwarzen Brett(e)s"
+    );
+    assert_eq!(
+        t.form(Kasus::Dativ, Numerus::Plural)[0],
+        "Schwarzen Brettern"
+    );
+}
+
+#[test]
+fn baum() {
+    let t = uebersicht(include_str!("snippets/baum.wiki"));
+    assert_eq!(t.genus[0], "m");
+    assert_eq!(t.form(Kasus::Nominativ, Numerus::Singular)[0], "Baum");
+    assert_eq!(
+        t.form(Kasus::Genitiv, Numerus::Singular),
+        ["Baumes", "Baums"]
+    );
+    assert_eq!(t.form(Kasus::Dativ, Numerus::Singular), ["Baum", "Baume"]);
+    assert_eq!(t.form(Kasus::Akkusativ, Numerus::Plural)[0], "Bäume");
+    assert!(!t.extra.contains_key("Genitiv Singular*"));
+    assert_eq!(t.extra["Bild"], "Baum 1.jpg");
+}
+
+#[test]
+fn wort() {
+    let t = uebersicht(include_str!("snippets/wort.wiki"));
+    assert_eq!(t.genus.len(), 1);
+    assert_eq!(
+        t.form(Kasus::Nominativ, Numerus::Plural),
+        ["Wörter", "Worte"]
+    );
+    assert_eq!(t.form(Kasus::Genitiv, Numerus::Singular)[1], "Worts");
+    assert_eq!(t.form(Kasus::Dativ, Numerus::Plural)[0], "Wörtern");
+    assert_eq!(t.form(Kasus::Akkusativ, Numerus::Singular).len(), 1);
+    assert!(t.extra.is_empty());
+}
+
+#[test]
+fn joghurt() {
+    let t = uebersicht(include_str!("snippets/joghurt.wiki"));
+    assert_eq!(t.genus, ["m", "n"]);
+    assert_eq!(t.form(Kasus::Nominativ, Numerus::Plural)[0], "Joghurts");
+    assert_eq!(t.form(Kasus::Nominativ, Numerus::Plural)[1], "Joghurt");
+    assert_eq!(t.form(Kasus::Akkusativ, Numerus::Plural).len(), 2);
+}
+
+#[test]
+fn see() {
+    let abschnitte = Substantivabschnitt::alle(include_str!("snippets/see.wiki"));
+    assert_eq!(abschnitte.len(), 2);
+
+    let der_see = &abschnitte[0];
+    assert_eq!(
+        der_see.ueberschrift,
+        "{{Wortart|Substantiv|Deutsch}}, {{m}}"
+    );
+    assert_eq!(der_see.uebersicht.genus[0], "m");
+    assert_eq!(
+        der_see.uebersicht.form(Kasus::Genitiv, Numerus::Singular)[0],
+        "Sees"
+    );
+    assert_eq!(der_see.bedeutungen.len(), 1);
+    assert_eq!(der_see.bedeutungen[0].nummer, "1");
+    assert_eq!(
+        der_see.bedeutungen[0].text,
+        "größeres stehendes Binnengewässer"
+    );
+
+    let die_see = &abschnitte[1];
+    assert_eq!(
+        die_see.ueberschrift,
+        "{{Wortart|Substantiv|Deutsch}}, {{f}}"
+    );
+    assert_eq!(die_see.uebersicht.genus[0], "f");
+    assert_eq!(
+        die_see.uebersicht.form(Kasus::Genitiv, Numerus::Singular)[0],
+        "See"
+    );
+    assert_eq!(die_see.bedeutungen.len(), 2);
+}
+
+#[test]
+fn kiefer() {
+    let abschnitte = Substantivabschnitt::alle(include_str!("snippets/kiefer.wiki"));
+    let genera: Vec<&str> = abschnitte
+        .iter()
+        .map(|a| a.uebersicht.genus[0].as_str())
+        .collect();
+    assert_eq!(genera, ["f", "m"]);
+    assert_eq!(abschnitte[1].bedeutungen.len(), 1);
+    assert_eq!(
+        abschnitte[1].bedeutungen[0].text,
+        "Knochen, der die Zähne trägt"
+    );
+}
+
+#[test]
+fn ein_abschnitt() {
+    let abschnitte = Substantivabschnitt::alle(include_str!("snippets/kopfnuss.wiki"));
+    assert_eq!(abschnitte.len(), 1);
+    assert_eq!(abschnitte[0].bedeutungen.len(), 2);
+}
+
+#[test]
+fn worttrennung() {
+    let abschnitte = Substantivabschnitt::alle(include_str!("snippets/kopfnuss.wiki"));
+    let worttrennung = &abschnitte[0].worttrennung;
+    assert_eq!(worttrennung.singular, [["Kopf", "nuss"]]);
+    assert_eq!(worttrennung.plural, [["Kopf", "nüs", "se"]]);
+
+    let abschnitte = Substantivabschnitt::alle(include_str!("snippets/wort.wiki"));
+    let worttrennung = &abschnitte[0].worttrennung;
+    assert_eq!(worttrennung.singular, [["Wort"]]);
+    assert_eq!(worttrennung.plural, [vec!["Wör", "ter"], vec!["Wor", "te"]]);
+
+    let abschnitte = Substantivabschnitt::alle(include_str!("snippets/e-mail.wiki"));
+    assert_eq!(abschnitte[0].worttrennung.plural, [["E-", "Mails"]]);
+}
+
+#[test]
+fn aussprache() {
+    let abschnitte = Substantivabschnitt::alle(include_str!("snippets/kopfnuss.wiki"));
+    let aussprache = &abschnitte[0].aussprache;
+    assert_eq!(aussprache.ipa_singular, ["ˈkɔp͡fˌnʊs"]);
+    assert_eq!(aussprache.audio_singular, ["De-Kopfnuss.ogg"]);
+    assert!(aussprache.ipa_plural.is_empty());
+    assert!(aussprache.reime.is_empty());
+
+    let abschnitte = Substantivabschnitt::alle(include_str!("snippets/wort.wiki"));
+    let aussprache = &abschnitte[0].aussprache;
+    assert_eq!(aussprache.ipa_plural, ["ˈvœʁtɐ", "ˈvɔʁtə"]);
+    assert_eq!(aussprache.reime, ["ɔʁt"]);
+}
+
+#[test]
+fn beispiele() {
+    let abschnitte = Substantivabschnitt::alle(include_str!("snippets/kopfnuss.wiki"));
+    let beispiele = &abschnitte[0].beispiele;
+    assert_eq!(beispiele.len(), 6);
+    assert!(beispiele.iter().all(|b| !b.text.contains("<ref>")));
+    assert_eq!(beispiele[5].bedeutungen, ["2"]);
+    assert_eq!(beispiele[5].text, "Dieses Rätsel war schon eine Kopfnuss.");
+    let maskierung = beispiele[5].maskiere(&["Kopfnuss"]).unwrap();
+    assert_eq!(maskierung.text, "Dieses Rätsel war schon ___ Kopfnuss.");
+
+    let abschnitte = Substantivabschnitt::alle(include_str!("snippets/see.wiki"));
+    let die_see = &abschnitte[1].beispiele;
+    assert_eq!(
+        die_see[0].maskiere(&["See"]).unwrap().text,
+        "In den Ferien fahren wir an ___ See."
+    );
+}
+
+#[test]
+fn uebersetzungen() {
+    let abschnitte = Substantivabschnitt::alle(include_str!("snippets/see.wiki"));
+    let der_see: Vec<(&str, &str)> = abschnitte[0]
+        .uebersetzungen
+        .iter()
+        .map(|u| (u.sprache.as_str(), u.text.as_str()))
+        .collect();
+    assert_eq!(der_see, [("en", "lake"), ("tr", "göl"), ("uk", "озеро")]);
+    assert_eq!(
+        abschnitte[0].uebersetzungen[2].umschrift.as_deref(),
+        Some("ozero")
+    );
+    assert_eq!(abschnitte[1].uebersetzungen[0].text, "sea");
+
+    let abschnitte = Substantivabschnitt::alle(include_str!("snippets/kopfnuss.wiki"));
+    let englisch: Vec<_> = abschnitte[0]
+        .uebersetzungen
+        .iter()
+        .filter(|u| u.sprache == "en")
+        .collect();
+    assert_eq!(englisch.len(), 1);
+    assert_eq!(englisch[0].text, "brain teaser");
+    assert_eq!(englisch[0].bedeutungen, ["2"]);
+}
+
+#[test]
+fn zusammensetzung() {
+    let abschnitte = Substantivabschnitt::alle(include_str!("snippets/kopfnuss.wiki"));
+    let zusammensetzung = abschnitte[0].zusammensetzung.as_ref().unwrap();
+    let teile: Vec<&str> = zusammensetzung
+        .teile
+        .iter()
+        .map(|t| t.wort.as_str())
+        .collect();
+    assert_eq!(teile, ["Kopf", "Nuss"]);
+    assert_eq!(zusammensetzung.kopf(), "Nuss");
+
+    let abschnitte = Substantivabschnitt::alle(include_str!("snippets/see.wiki"));
+    assert_eq!(abschnitte[0].zusammensetzung, None);
+}
+
+#[test]
+fn beziehungen() {
+    let abschnitte = Substantivabschnitt::alle(include_str!("snippets/see.wiki"));
+    let der_see: Vec<(Beziehungsart, &str)> = abschnitte[0]
+        .beziehungen
+        .iter()
+        .map(|b| (b.art, b.ziel.as_str()))
+        .collect();
+    assert_eq!(
+        der_see,
+        [
+            (Beziehungsart::Oberbegriff, "Gewässer"),
+            (Beziehungsart::Unterbegriff, "Bergsee"),
+            (Beziehungsart::Unterbegriff, "Stausee"),
+        ]
+    );
+    let die_see: Vec<&str> = abschnitte[1]
+        .beziehungen
+        .iter()
+        .map(|b| b.ziel.as_str())
+        .collect();
+    assert_eq!(die_see, ["Meer", "Ozean"]);
+}
+
+#[test]
+fn schwimmen() {
+    let seite = include_str!("snippets/schwimmen.wiki");
+    let verben = Verbabschnitt::alle(seite);
+    assert_eq!(verben.len(), 1);
+    let t = &verben[0].uebersicht;
+    assert_eq!(verben[0].infinitiv, "schwimmen");
+    assert_eq!(t.praeteritum_ich, ["schwamm"]);
+    assert_eq!(t.partizip_ii, ["geschwommen"]);
+    assert_eq!(t.konjunktiv_ii_ich, ["schwömme", "schwämme"]);
+    assert_eq!(t.hilfsverb, ["sein", "haben"]);
+    assert_eq!(t.extra["Bild"], "Swimming pool.jpg");
+    assert_eq!(verben[0].bedeutungen.len(), 2);
+
+    let substantive = Substantivabschnitt::alle(seite);
+    assert_eq!(substantive.len(), 1);
+    assert_eq!(
+        substantive[0]
+            .uebersicht
+            .form(Kasus::Nominativ, Numerus::Singular)[0],
+        "Schwimmen"
+    );
+    assert_eq!(substantive[0].uebersicht.tantum, Some(Tantum::Singular));
+    assert!(substantive[0]
+        .uebersicht
+        .form(Kasus::Nominativ, Numerus::Plural)
+        .is_empty());
+}
+
+#[test]
+fn gross() {
+    let seite = include_str!("snippets/gross.wiki");
+    let adjektive = Adjektivabschnitt::alle(seite);
+    assert_eq!(adjektive.len(), 1);
+    let t = &adjektive[0].uebersicht;
+    assert_eq!(t.positiv, ["groß"]);
+    assert_eq!(t.komparativ, ["größer"]);
+    assert_eq!(t.superlativ, ["größten"]);
+    assert_eq!(adjektive[0].bedeutungen.len(), 2);
+    assert!(Substantivabschnitt::alle(seite).is_empty());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn json() {
+    for seite in [
+        include_str!("snippets/kopfnuss.wiki"),
+        include_str!("snippets/see.wiki"),
+    ] {
+        for abschnitt in Substantivabschnitt::alle(seite) {
+            let json = serde_json::to_string(&abschnitt).unwrap();
+            let zurueck: Substantivabschnitt = serde_json::from_str(&json).unwrap();
+            assert_eq!(zurueck, abschnitt);
+        }
+    }
+    let verben = Verbabschnitt::alle(include_str!("snippets/schwimmen.wiki"));
+    let json = serde_json::to_value(&verben[0]).unwrap();
+    assert_eq!(json["uebersicht"]["hilfsverb"][0], "sein");
+}
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE derdiedas_form;
+DROP TABLE derdiedas_genus;
@@ -0,0 +1,16 @@
+-- Store every gender and every numbered case form of a noun.
+-- `derdiedas` keeps the first gender and the first form of each case.
+CREATE TABLE derdiedas_genus (
+  nominativ_singular TEXT NOT NULL REFERENCES derdiedas (nominativ_singular),
+  genus TEXT NOT NULL,
+  PRIMARY KEY (nominativ_singular, genus)
+);
+
+CREATE TABLE derdiedas_form (
+  nominativ_singular TEXT NOT NULL REFERENCES derdiedas (nominativ_singular),
+  kasus TEXT NOT NULL,
+  numerus TEXT NOT NULL,
+  nummer INTEGER NOT NULL,
+  form TEXT NOT NULL,
+  PRIMARY KEY (nominativ_singular, kasus, numerus, nummer)
+);
@@ -0,0 +1,52 @@
+-- This file should undo anything in `up.sql`
+-- Only the first homonym of a word survives, as the word is the primary key again.
+CREATE TABLE derdiedas_alt (
+  nominativ_singular TEXT NOT NULL PRIMARY KEY,
+  genus TEXT NOT NULL,
+  nominativ_plural TEXT,
+  genitiv_singular TEXT,
+  genitiv_plural TEXT,
+  dativ_singular TEXT,
+  dativ_plural TEXT,
+  akkusativ_singular TEXT,
+  akkusativ_plural TEXT
+);
+
+INSERT OR IGNORE INTO derdiedas_alt (nominativ_singular, genus, nominativ_plural,
+  genitiv_singular, genitiv_plural, dativ_singular, dativ_plural, akkusativ_singular,
+  akkusativ_plural)
+SELECT nominativ_singular, genus, nominativ_plural, genitiv_singular,
+  genitiv_plural, dativ_singular, dativ_plural, akkusativ_singular, akkusativ_plural
+FROM derdiedas ORDER BY id;
+
+CREATE TABLE derdiedas_genus_alt (
+  nominativ_singular TEXT NOT NULL REFERENCES derdiedas_alt (nominativ_singular),
+  genus TEXT NOT NULL,
+  PRIMARY KEY (nominativ_singular, genus)
+);
+
+INSERT OR IGNORE INTO derdiedas_genus_alt (nominativ_singular, genus)
+SELECT d.nominativ_singular, g.genus
+FROM derdiedas_genus g JOIN derdiedas d ON d.id = g.derdiedas_id ORDER BY d.id;
+
+CREATE TABLE derdiedas_form_alt (
+  nominativ_singular TEXT NOT NULL REFERENCES derdiedas_alt (nominativ_singular),
+  kasus TEXT NOT NULL,
+  numerus TEXT NOT NULL,
+  nummer INTEGER NOT NULL,
+  form TEXT NOT NULL,
+  PRIMARY KEY (nominativ_singular, kasus, numerus, nummer)
+);
+
+INSERT OR IGNORE INTO derdiedas_form_alt (nominativ_singular, kasus, numerus, nummer, form)
+SELECT d.nominativ_singular, f.kasus, f.numerus, f.nummer, f.form
+FROM derdiedas_form f JOIN derdiedas d ON d.id = f.derdiedas_id ORDER BY d.id;
+
+DROP INDEX derdiedas_nominativ_singular;
+DROP TABLE derdiedas_form;
+DROP TABLE derdiedas_genus;
+DROP TABLE derdiedas;
+
+ALTER TABLE derdiedas_alt RENAME TO derdiedas;
+ALTER TABLE derdiedas_genus_alt RENAME TO derdiedas_genus;
+ALTER TABLE derdiedas_form_alt RENAME TO derdiedas_form;
@@ -0,0 +1,53 @@
+-- Homonyms like "der See" and "die See" share their nominative singular, so every entry
+-- gets its own id instead of using the word as the primary key.
+CREATE TABLE derdiedas_neu (
+  id INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+  nominativ_singular TEXT NOT NULL,
+  genus TEXT NOT NULL,
+  nominativ_plural TEXT,
+  genitiv_singular TEXT,
+  genitiv_plural TEXT,
+  dativ_singular TEXT,
+  dativ_plural TEXT,
+  akkusativ_singular TEXT,
+  akkusativ_plural TEXT
+);
+
+INSERT INTO derdiedas_neu (nominativ_singular, genus, nominativ_plural, genitiv_singular,
+  genitiv_plural, dativ_singular, dativ_plural, akkusativ_singular, akkusativ_plural)
+SELECT nominativ_singular, genus, nominativ_plural, genitiv_singular,
+  genitiv_plural, dativ_singular, dativ_plural, akkusativ_singular, akkusativ_plural
+FROM derdiedas;
+
+CREATE TABLE derdiedas_genus_neu (
+  derdiedas_id INTEGER NOT NULL REFERENCES derdiedas_neu (id),
+  genus TEXT NOT NULL,
+  PRIMARY KEY (derdiedas_id, genus)
+);
+
+INSERT INTO derdiedas_genus_neu (derdiedas_id, genus)
+SELECT d.id, g.genus
+FROM derdiedas_genus g JOIN derdiedas_neu d ON d.nominativ_singular = g.nominativ_singular;
+
+CREATE TABLE derdiedas_form_neu (
+  derdiedas_id INTEGER NOT NULL REFERENCES derdiedas_neu (id),
+  kasus TEXT NOT NULL,
+  numerus TEXT NOT NULL,
+  nummer INTEGER NOT NULL,
+  form TEXT NOT NULL,
+  PRIMARY KEY (derdiedas_id, kasus, numerus, nummer)
+);
+
+INSERT INTO derdiedas_form_neu (derdiedas_id, kasus, numerus, nummer, form)
+SELECT d.id, f.kasus, f.numerus, f.nummer, f.form
+FROM derdiedas_form f JOIN derdiedas_neu d ON d.nominativ_singular = f.nominativ_singular;
+
+DROP TABLE derdiedas_form;
+DROP TABLE derdiedas_genus;
+DROP TABLE derdiedas;
+
+ALTER TABLE derdiedas_neu RENAME TO derdiedas;
+ALTER TABLE derdiedas_genus_neu RENAME TO derdiedas_genus;
+ALTER TABLE derdiedas_form_neu RENAME TO derdiedas_form;
+
+CREATE INDEX derdiedas_nominativ_singular ON derdiedas (nominativ_singular);
@@ -0,0 +1,9 @@
+-- The syllables of every singular and plural form from the {{Worttrennung}} block, joined
+-- with "·" as in the dump, e.g. "Kopf·nüs·se".
+CREATE TABLE derdiedas_worttrennung (
+  derdiedas_id INTEGER NOT NULL REFERENCES derdiedas (id),
+  numerus TEXT NOT NULL,
+  nummer INTEGER NOT NULL,
+  silben TEXT NOT NULL,
+  PRIMARY KEY (derdiedas_id, numerus, nummer)
+);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE derdiedas_reim;
+DROP TABLE derdiedas_audio;
+DROP TABLE derdiedas_lautschrift;
@@ -0,0 +1,25 @@
+-- The {{Aussprache}} block of a noun: IPA and audio files of every singular and plural form,
+-- and its rhyme keys.
+CREATE TABLE derdiedas_lautschrift (
+  derdiedas_id INTEGER NOT NULL REFERENCES derdiedas (id),
+  numerus TEXT NOT NULL,
+  nummer INTEGER NOT NULL,
+  ipa TEXT NOT NULL,
+  PRIMARY KEY (derdiedas_id, numerus, nummer)
+);
+
+CREATE TABLE derdiedas_audio (
+  derdiedas_id INTEGER NOT NULL REFERENCES derdiedas (id),
+  numerus TEXT NOT NULL,
+  nummer INTEGER NOT NULL,
+  datei TEXT NOT NULL,
+  PRIMARY KEY (derdiedas_id, numerus, nummer)
+);
+
+CREATE TABLE derdiedas_reim (
+  derdiedas_id INTEGER NOT NULL REFERENCES derdiedas (id),
+  reim TEXT NOT NULL,
+  PRIMARY KEY (derdiedas_id, reim)
+);
+
+CREATE INDEX derdiedas_reim_reim ON derdiedas_reim (reim);
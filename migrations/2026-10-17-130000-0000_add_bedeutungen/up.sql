@@ -0,0 +1,7 @@
+-- The numbered meanings of a noun as plain text, e.g. "2" and "Knochen, der die Zähne trägt".
+CREATE TABLE derdiedas_bedeutung (
+  derdiedas_id INTEGER NOT NULL REFERENCES derdiedas (id),
+  nummer TEXT NOT NULL,
+  text TEXT NOT NULL,
+  PRIMARY KEY (derdiedas_id, nummer)
+);
@@ -0,0 +1,12 @@
+-- The example sentences of a noun without their references. `bedeutungen` holds the meaning
+-- numbers, e.g. "1, 2". `maskiert` is the sentence with the article of the noun replaced by
+-- "___", and `artikel` is the article that was replaced.
+CREATE TABLE derdiedas_beispiel (
+  derdiedas_id INTEGER NOT NULL REFERENCES derdiedas (id),
+  nummer INTEGER NOT NULL,
+  bedeutungen TEXT NOT NULL,
+  text TEXT NOT NULL,
+  artikel TEXT,
+  maskiert TEXT,
+  PRIMARY KEY (derdiedas_id, nummer)
+);
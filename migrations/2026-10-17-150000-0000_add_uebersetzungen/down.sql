@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX derdiedas_uebersetzung_sprache;
+DROP TABLE derdiedas_uebersetzung;
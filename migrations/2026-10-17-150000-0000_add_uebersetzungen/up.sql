@@ -0,0 +1,14 @@
+-- The translations of a noun from its `{{Ü-Tabelle}}` blocks. `nummer` counts the
+-- translations per language, `bedeutungen` holds the meaning numbers, e.g. "1, 2", and
+-- `umschrift` the transliteration of a non-Latin script.
+CREATE TABLE derdiedas_uebersetzung (
+  derdiedas_id INTEGER NOT NULL REFERENCES derdiedas (id),
+  sprache TEXT NOT NULL,
+  nummer INTEGER NOT NULL,
+  bedeutungen TEXT NOT NULL,
+  text TEXT NOT NULL,
+  umschrift TEXT,
+  PRIMARY KEY (derdiedas_id, sprache, nummer)
+);
+
+CREATE INDEX derdiedas_uebersetzung_sprache ON derdiedas_uebersetzung (sprache);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP VIEW derdiedas_kompositum;
+DROP INDEX derdiedas_bestandteil_wort;
+DROP TABLE derdiedas_bestandteil;
@@ -0,0 +1,20 @@
+-- The parts of a compound as its `{{Herkunft}}` gives them, in the order of the word. `fuge`
+-- is the linking element after the part, e.g. "s" for "Arbeit" in "Arbeitszimmer".
+CREATE TABLE derdiedas_bestandteil (
+  derdiedas_id INTEGER NOT NULL REFERENCES derdiedas (id),
+  nummer INTEGER NOT NULL,
+  wort TEXT NOT NULL,
+  fuge TEXT,
+  PRIMARY KEY (derdiedas_id, nummer)
+);
+
+CREATE INDEX derdiedas_bestandteil_wort ON derdiedas_bestandteil (wort);
+
+-- Links a compound to the entries of its head word, its last part.
+CREATE VIEW derdiedas_kompositum AS
+SELECT b.derdiedas_id AS kompositum_id, d.id AS kopf_id
+FROM derdiedas_bestandteil b
+JOIN derdiedas d ON d.nominativ_singular = b.wort
+WHERE b.nummer = (
+  SELECT MAX(nummer) FROM derdiedas_bestandteil WHERE derdiedas_id = b.derdiedas_id
+);
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX derdiedas_beziehung_art_ziel;
+DROP TABLE derdiedas_beziehung;
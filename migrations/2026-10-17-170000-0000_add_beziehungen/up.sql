@@ -0,0 +1,13 @@
+-- The lexical relations of a noun to other lemmas. `art` is one of "synonym", "gegenwort",
+-- "oberbegriff", "unterbegriff" and "sinnverwandt", and `bedeutungen` holds the meaning
+-- numbers, e.g. "1, 2".
+CREATE TABLE derdiedas_beziehung (
+  derdiedas_id INTEGER NOT NULL REFERENCES derdiedas (id),
+  nummer INTEGER NOT NULL,
+  art TEXT NOT NULL,
+  bedeutungen TEXT NOT NULL,
+  ziel TEXT NOT NULL,
+  PRIMARY KEY (derdiedas_id, nummer)
+);
+
+CREATE INDEX derdiedas_beziehung_art_ziel ON derdiedas_beziehung (art, ziel);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE verb_form;
+DROP INDEX verb_infinitiv;
+DROP TABLE verb;
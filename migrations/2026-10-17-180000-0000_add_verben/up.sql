@@ -0,0 +1,26 @@
+-- German verbs from the `{{Deutsch Verb Übersicht}}` tables. `verb` keeps the first form of
+-- every parameter, and `verb_form` keeps all of them, e.g. both auxiliaries of "schwimmen".
+CREATE TABLE verb (
+  id INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+  infinitiv TEXT NOT NULL,
+  hilfsverb TEXT,
+  praesens_ich TEXT,
+  praesens_du TEXT,
+  praesens_er_sie_es TEXT,
+  praeteritum_ich TEXT,
+  konjunktiv_ii_ich TEXT,
+  partizip_ii TEXT,
+  imperativ_singular TEXT,
+  imperativ_plural TEXT
+);
+
+CREATE INDEX verb_infinitiv ON verb (infinitiv);
+
+-- `form` is the column of `verb` the text belongs to, e.g. "hilfsverb" or "partizip_ii".
+CREATE TABLE verb_form (
+  verb_id INTEGER NOT NULL REFERENCES verb (id),
+  form TEXT NOT NULL,
+  nummer INTEGER NOT NULL,
+  text TEXT NOT NULL,
+  PRIMARY KEY (verb_id, form, nummer)
+);
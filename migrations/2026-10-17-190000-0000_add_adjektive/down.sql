@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE adjektiv_form;
+DROP INDEX adjektiv_positiv;
+DROP TABLE adjektiv;
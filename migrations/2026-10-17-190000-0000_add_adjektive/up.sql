@@ -0,0 +1,20 @@
+-- German adjectives from the `{{Deutsch Adjektiv Übersicht}}` tables. `adjektiv` keeps the
+-- first form of every degree, and `adjektiv_form` keeps all of them. The superlative is
+-- stored without "am", e.g. "größten".
+CREATE TABLE adjektiv (
+  id INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+  positiv TEXT NOT NULL,
+  komparativ TEXT,
+  superlativ TEXT
+);
+
+CREATE INDEX adjektiv_positiv ON adjektiv (positiv);
+
+-- `stufe` is "positiv", "komparativ" or "superlativ".
+CREATE TABLE adjektiv_form (
+  adjektiv_id INTEGER NOT NULL REFERENCES adjektiv (id),
+  stufe TEXT NOT NULL,
+  nummer INTEGER NOT NULL,
+  text TEXT NOT NULL,
+  PRIMARY KEY (adjektiv_id, stufe, nummer)
+);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+-- The "—" placeholders that were replaced by NULL are not restored.
+ALTER TABLE derdiedas DROP COLUMN tantum;
+ALTER TABLE derdiedas DROP COLUMN art;
@@ -0,0 +1,16 @@
+-- `art` is the kind of noun table: "substantiv", "adjektivisch", "eigenname" or "toponym".
+-- `tantum` is "singular" for nouns without a plural and "plural" for nouns without a singular.
+ALTER TABLE derdiedas ADD COLUMN art TEXT NOT NULL DEFAULT 'substantiv';
+ALTER TABLE derdiedas ADD COLUMN tantum TEXT;
+
+-- Forms that do not exist used to be stored as the placeholder "—".
+UPDATE derdiedas SET tantum = 'singular'
+WHERE nominativ_plural = '—' AND nominativ_singular <> '—';
+UPDATE derdiedas SET nominativ_plural = NULLIF(nominativ_plural, '—'),
+  genitiv_singular = NULLIF(genitiv_singular, '—'),
+  genitiv_plural = NULLIF(genitiv_plural, '—'),
+  dativ_singular = NULLIF(dativ_singular, '—'),
+  dativ_plural = NULLIF(dativ_plural, '—'),
+  akkusativ_singular = NULLIF(akkusativ_singular, '—'),
+  akkusativ_plural = NULLIF(akkusativ_plural, '—');
+DELETE FROM derdiedas_form WHERE form = '—';
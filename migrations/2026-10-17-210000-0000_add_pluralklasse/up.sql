@@ -0,0 +1,3 @@
+-- `pluralklasse` is how the plural is formed, e.g. "e" for "Tag – Tage" or "er_umlaut" for
+-- "Haus – Häuser". Nouns without a plural have none. Existing rows get it on the next import.
+ALTER TABLE derdiedas ADD COLUMN pluralklasse TEXT;
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DELETE FROM derdiedas_worttrennung WHERE berechnet;
+ALTER TABLE derdiedas_worttrennung DROP COLUMN berechnet;
@@ -0,0 +1,3 @@
+-- `berechnet` marks syllables the importer computed with hyphenation patterns because the
+-- page has no `{{Worttrennung}}` block.
+ALTER TABLE derdiedas_worttrennung ADD COLUMN berechnet BOOLEAN NOT NULL DEFAULT 0;
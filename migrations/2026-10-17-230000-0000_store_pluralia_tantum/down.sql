@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+UPDATE derdiedas SET tantum = NULL, nominativ_singular = '—'
+WHERE tantum = 'plural';
@@ -0,0 +1,5 @@
+-- Pluralia tantum like "Leute" are stored with their nominative plural as the headword in
+-- `nominativ_singular`, `tantum` "plural" and the gender "0" of Wiktionary if they have none.
+-- Rows imported before kept the placeholder "—" as the headword.
+UPDATE derdiedas SET tantum = 'plural', nominativ_singular = nominativ_plural
+WHERE nominativ_singular = '—' AND nominativ_plural IS NOT NULL;
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX derdiedas_titel_abschnitt;
+ALTER TABLE derdiedas DROP COLUMN abschnitt;
+ALTER TABLE derdiedas DROP COLUMN titel;
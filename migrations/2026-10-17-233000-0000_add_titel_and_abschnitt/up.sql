@@ -0,0 +1,8 @@
+-- `titel` is the page of the dump a noun comes from and `abschnitt` the number of its noun
+-- section on that page, counted from 1. Together they tell homonyms apart that share the word,
+-- the gender and the kind of table, like the two "die Bank" with the plurals "Bänke" and
+-- "Banken", and they keep a second import of the same dump from adding every noun again.
+-- Rows imported before have neither.
+ALTER TABLE derdiedas ADD COLUMN titel TEXT;
+ALTER TABLE derdiedas ADD COLUMN abschnitt INTEGER;
+CREATE UNIQUE INDEX derdiedas_titel_abschnitt ON derdiedas (titel, abschnitt);
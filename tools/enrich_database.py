@@ -103,6 +103,29 @@ def detect_category(word):
 
     return None
 
+def load_oberbegriffe(cursor):
+    """
+    Load the hypernyms of every word, as imported from the {{Oberbegriffe}} blocks of the
+    dump by dewiktionary-importer-cli.
+    Returns an empty dict for databases without that table
+    """
+    cursor.execute(
+        "SELECT name FROM sqlite_master WHERE type = 'table' AND name = 'derdiedas_beziehung'"
+    )
+    if cursor.fetchone() is None:
+        return {}
+
+    cursor.execute("""
+        SELECT d.nominativ_singular, b.ziel
+        FROM derdiedas_beziehung b JOIN derdiedas d ON d.id = b.derdiedas_id
+        WHERE b.art = 'oberbegriff'
+        ORDER BY d.id, b.nummer
+    """)
+    oberbegriffe = {}
+    for word, ziel in cursor.fetchall():
+        oberbegriffe.setdefault(word, []).append(ziel)
+    return oberbegriffe
+
 def is_compound_word(word):
     """
     Detect if word is likely a compound
@@ -147,6 +170,30 @@ def split_compound(word):
 
     return None
 
+def load_worttrennung(cursor):
+    """
+    Load the syllables of the first singular form of every word, as imported from the
+    {{Worttrennung}} blocks of the dump by dewiktionary-importer-cli. Words without such a
+    block get syllables computed by the importer's hyphenation patterns.
+    Returns an empty dict for databases without that table
+    """
+    cursor.execute(
+        "SELECT name FROM sqlite_master WHERE type = 'table' AND name = 'derdiedas_worttrennung'"
+    )
+    if cursor.fetchone() is None:
+        return {}
+
+    cursor.execute("""
+        SELECT d.nominativ_singular, w.silben
+        FROM derdiedas_worttrennung w JOIN derdiedas d ON d.id = w.derdiedas_id
+        WHERE w.numerus = 'singular' AND w.nummer = 1
+        ORDER BY d.id
+    """)
+    worttrennung = {}
+    for word, silben in cursor.fetchall():
+        worttrennung.setdefault(word, silben.split('·'))
+    return worttrennung
+
 def calculate_difficulty(word, syllable_count):
     """
     Calculate difficulty level (1-5) based on word characteristics
@@ -204,6 +251,10 @@ def enrich_database(db_path, verbose=True):
 
     conn.commit()
 
+    # Real syllable breaks and hypernyms from the dump win over the heuristics
+    worttrennung = load_worttrennung(cursor)
+    oberbegriffe = load_oberbegriffe(cursor)
+
     # Get all words
     cursor.execute("SELECT nominativ_singular FROM derdiedas")
     words = cursor.fetchall()
@@ -223,9 +274,18 @@ def enrich_database(db_path, verbose=True):
         if verbose and i % 5000 == 0:
             print(f"    Progress: {i:,}/{total:,} ({i*100//total}%)")
 
-        syllable_count = count_syllables(word)
-        syllables = create_syllable_breaks(word)
-        category = detect_category(word)
+        if word in worttrennung:
+            syllable_count = len(worttrennung[word])
+            # "E-·Mail" keeps the hyphen of the word on its first syllable
+            syllables = '-'.join(silbe.rstrip('-') for silbe in worttrennung[word])
+        else:
+            syllable_count = count_syllables(word)
+            syllables = create_syllable_breaks(word)
+        # A hypernym like "Nadelbaum" names the category better than the letters of the word
+        category = next(
+            (c for c in map(detect_category, oberbegriffe.get(word, [])) if c),
+            None,
+        ) or detect_category(word)
         is_comp = is_compound_word(word)
         comp_parts = split_compound(word) if is_comp else None
         difficulty = calculate_difficulty(word, syllable_count)